@@ -14,12 +14,19 @@ pub const X25_BYTEWISE: Crc<u16, Table<1>> = Crc::<u16, Table<1>>::new(&CRC_16_I
 pub const X25_NOLOOKUP: Crc<u16, NoTable> = Crc::<u16, NoTable>::new(&CRC_16_IBM_SDLC);
 pub const ISCSI: Crc<u32> = Crc::<u32>::new(&CRC_32_ISCSI);
 pub const ISCSI_SLICE16: Crc<u32, Table<16>> = Crc::<u32, Table<16>>::new(&CRC_32_ISCSI);
+pub const ISCSI_SLICE8: Crc<u32, Table<8>> = Crc::<u32, Table<8>>::new(&CRC_32_ISCSI);
 pub const ISCSI_BYTEWISE: Crc<u32, Table<1>> = Crc::<u32, Table<1>>::new(&CRC_32_ISCSI);
+pub const ISCSI_BRAID: Crc<u32, Braid<5>> = Crc::<u32, Braid<5>>::new(&CRC_32_ISCSI);
+pub const ISCSI_NIBBLE: Crc<u32, NibbleTable> = Crc::<u32, NibbleTable>::new(&CRC_32_ISCSI);
+pub const ISCSI_BARRETT: Crc<u32, Barrett> = Crc::<u32, Barrett>::new(&CRC_32_ISCSI);
+pub const ISCSI_CHORBA: Crc<u32, Chorba> = Crc::<u32, Chorba>::new(&CRC_32_ISCSI);
 pub const ISCSI_NOLOOKUP: Crc<u32, NoTable> = Crc::<u32, NoTable>::new(&CRC_32_ISCSI);
 pub const GSM_40: Crc<u64> = Crc::<u64>::new(&CRC_40_GSM);
 pub const ECMA: Crc<u64> = Crc::<u64>::new(&CRC_64_ECMA_182);
 pub const ECMA_SLICE16: Crc<u64, Table<16>> = Crc::<u64, Table<16>>::new(&CRC_64_ECMA_182);
+pub const ECMA_BRAID: Crc<u64, Braid<5>> = Crc::<u64, Braid<5>>::new(&CRC_64_ECMA_182);
 pub const ECMA_BYTEWISE: Crc<u64, Table<1>> = Crc::<u64, Table<1>>::new(&CRC_64_ECMA_182);
+pub const ECMA_BARRETT: Crc<u64, Barrett> = Crc::<u64, Barrett>::new(&CRC_64_ECMA_182);
 pub const ECMA_NOLOOKUP: Crc<u64, NoTable> = Crc::<u64, NoTable>::new(&CRC_64_ECMA_182);
 pub const DARC: Crc<u128> = Crc::<u128>::new(&CRC_82_DARC);
 pub const DARC_SLICE16: Crc<u128, Table<16>> = Crc::<u128, Table<16>>::new(&CRC_82_DARC);
@@ -101,6 +108,30 @@ fn bench_crc32_nolookup(b: &mut Bencher) {
     b.iter(|| ISCSI_NOLOOKUP.checksum(black_box(&bytes)));
 }
 
+#[bench]
+fn bench_crc32_barrett(b: &mut Bencher) {
+    let size = 16 * KB;
+    let bytes = vec![0u8; size];
+    b.bytes = size as u64;
+    b.iter(|| ISCSI_BARRETT.checksum(black_box(&bytes)));
+}
+
+#[bench]
+fn bench_crc32_chorba(b: &mut Bencher) {
+    let size = 16 * KB;
+    let bytes = vec![0u8; size];
+    b.bytes = size as u64;
+    b.iter(|| ISCSI_CHORBA.checksum(black_box(&bytes)));
+}
+
+#[bench]
+fn bench_crc32_nibble(b: &mut Bencher) {
+    let size = 16 * KB;
+    let bytes = vec![0u8; size];
+    b.bytes = size as u64;
+    b.iter(|| ISCSI_NIBBLE.checksum(black_box(&bytes)));
+}
+
 #[bench]
 fn bench_crc32_bytewise(b: &mut Bencher) {
     let size = 16 * KB;
@@ -109,6 +140,14 @@ fn bench_crc32_bytewise(b: &mut Bencher) {
     b.iter(|| ISCSI_BYTEWISE.checksum(black_box(&bytes)));
 }
 
+#[bench]
+fn bench_crc32_slice8(b: &mut Bencher) {
+    let size = 16 * KB;
+    let bytes = vec![0u8; size];
+    b.bytes = size as u64;
+    b.iter(|| ISCSI_SLICE8.checksum(black_box(&bytes)));
+}
+
 #[bench]
 fn bench_crc32_slice16(b: &mut Bencher) {
     let size = 16 * KB;
@@ -117,6 +156,34 @@ fn bench_crc32_slice16(b: &mut Bencher) {
     b.iter(|| ISCSI_SLICE16.checksum(black_box(&bytes)));
 }
 
+#[bench]
+fn bench_crc32_braid(b: &mut Bencher) {
+    let size = 16 * KB;
+    let bytes = vec![0u8; size];
+    b.bytes = size as u64;
+    b.iter(|| ISCSI_BRAID.checksum(black_box(&bytes)));
+}
+
+#[bench]
+fn bench_crc32_bytewise_frames(b: &mut Bencher) {
+    let frames = vec![[0u8; 64]; 256];
+    b.bytes = (frames.len() * 64) as u64;
+    b.iter(|| {
+        for frame in black_box(&frames) {
+            black_box(ISCSI_BYTEWISE.checksum(frame));
+        }
+    });
+}
+
+#[bench]
+fn bench_crc32_bytewise_many(b: &mut Bencher) {
+    let frames = vec![[0u8; 64]; 256];
+    let inputs: Vec<&[u8]> = frames.iter().map(|frame| &frame[..]).collect();
+    let mut out = vec![0; inputs.len()];
+    b.bytes = (frames.len() * 64) as u64;
+    b.iter(|| ISCSI_BYTEWISE.checksum_many(black_box(&inputs), &mut out));
+}
+
 // CRC-64 benchmarks
 #[bench]
 fn bench_crc64_nolookup(b: &mut Bencher) {
@@ -126,6 +193,14 @@ fn bench_crc64_nolookup(b: &mut Bencher) {
     b.iter(|| ECMA_NOLOOKUP.checksum(black_box(&bytes)));
 }
 
+#[bench]
+fn bench_crc64_barrett(b: &mut Bencher) {
+    let size = 16 * KB;
+    let bytes = vec![0u8; size];
+    b.bytes = size as u64;
+    b.iter(|| ECMA_BARRETT.checksum(black_box(&bytes)));
+}
+
 #[bench]
 fn bench_crc64_bytewise(b: &mut Bencher) {
     let size = 16 * KB;
@@ -142,6 +217,14 @@ fn bench_crc64_slice16(b: &mut Bencher) {
     b.iter(|| ECMA_SLICE16.checksum(black_box(&bytes)));
 }
 
+#[bench]
+fn bench_crc64_braid(b: &mut Bencher) {
+    let size = 16 * KB;
+    let bytes = vec![0u8; size];
+    b.bytes = size as u64;
+    b.iter(|| ECMA_BRAID.checksum(black_box(&bytes)));
+}
+
 // CRC-82 benchmarks
 #[bench]
 fn bench_crc82_nolookup(b: &mut Bencher) {
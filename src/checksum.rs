@@ -0,0 +1,173 @@
+use core::fmt;
+use core::ops::Deref;
+
+use crate::poly::{parse_hex, ParsePolyError};
+
+/// A finalized CRC value together with the `width` of its algorithm, which is formatted
+/// as hex zero-padded to `ceil(width / 4)` digits and converted to `ceil(width / 8)` bytes.
+///
+/// ```rust
+/// use crc::{Checksum, Crc, CRC_16_DECT_X, CRC_32_ISO_HDLC};
+///
+/// const HDLC: Crc<u32> = Crc::<u32>::new(&CRC_32_ISO_HDLC);
+/// let checksum = HDLC.typed_checksum(b"123456789");
+/// assert_eq!(checksum.to_string(), "cbf43926");
+/// assert_eq!(format!("{checksum:#X}"), "0xCBF43926");
+/// assert_eq!(*checksum.to_le_bytes(), [0x26, 0x39, 0xf4, 0xcb]);
+///
+/// const DECT_X: Crc<u16> = Crc::<u16>::new(&CRC_16_DECT_X);
+/// let mut digest = DECT_X.digest();
+/// digest.update(b"123456789");
+/// let checksum = digest.finalize_typed();
+/// assert_eq!(checksum.to_string(), "007f");
+/// assert_eq!(Checksum::<u16>::from_hex("0x007f", 16), Ok(checksum));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Checksum<W> {
+    pub(crate) value: W,
+    pub(crate) width: u8,
+}
+
+/// The bytes of a [`Checksum`], dereferencing to a slice of `ceil(width / 8)` bytes.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ChecksumBytes {
+    bytes: [u8; 16],
+    len: u8,
+}
+
+impl<W: Copy + Into<u128>> Checksum<W> {
+    /// Wraps a checksum `value` of an algorithm with the given `width`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is zero or exceeds the bit width of `W`, or if `value` has bits
+    /// set above `width`.
+    pub fn new(value: W, width: u8) -> Self {
+        assert!(
+            width != 0 && width as usize <= size_of::<W>() * 8,
+            "width out of range"
+        );
+        assert!(
+            value.into() & !mask(width) == 0,
+            "value has bits set above width"
+        );
+        Self { value, width }
+    }
+
+    /// Parses a checksum of `width` bits from hex, with an optional `0x` prefix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is zero or exceeds the bit width of `W`.
+    pub fn from_hex(src: &str, width: u8) -> Result<Self, ParsePolyError>
+    where
+        W: TryFrom<u128>,
+    {
+        assert!(
+            width != 0 && width as usize <= size_of::<W>() * 8,
+            "width out of range"
+        );
+        let value = parse_hex(src, width)?;
+        match W::try_from(value) {
+            Ok(value) => Ok(Self { value, width }),
+            Err(_) => Err(ParsePolyError::Overflow),
+        }
+    }
+
+    pub const fn value(&self) -> W {
+        self.value
+    }
+
+    pub const fn width(&self) -> u8 {
+        self.width
+    }
+
+    /// Big-endian bytes of the checksum, truncated to `ceil(width / 8)` bytes.
+    pub fn to_be_bytes(&self) -> ChecksumBytes {
+        let len = self.len();
+        let mut bytes = [0; 16];
+        bytes[..len].copy_from_slice(&self.value.into().to_be_bytes()[16 - len..]);
+        ChecksumBytes {
+            bytes,
+            len: len as u8,
+        }
+    }
+
+    /// Little-endian bytes of the checksum, truncated to `ceil(width / 8)` bytes.
+    pub fn to_le_bytes(&self) -> ChecksumBytes {
+        let len = self.len();
+        let mut bytes = self.value.into().to_le_bytes();
+        bytes[len..].fill(0);
+        ChecksumBytes {
+            bytes,
+            len: len as u8,
+        }
+    }
+
+    /// Number of bytes of the checksum.
+    fn len(&self) -> usize {
+        (self.width as usize).div_ceil(8)
+    }
+
+    /// Number of hex digits of the checksum.
+    fn digits(&self) -> usize {
+        (self.width as usize).div_ceil(4)
+    }
+}
+
+const fn mask(width: u8) -> u128 {
+    u128::MAX >> (128 - width as u32)
+}
+
+impl<W: Copy + Into<u128>> fmt::Display for Checksum<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+impl<W: Copy + Into<u128>> fmt::LowerHex for Checksum<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        write!(f, "{:0digits$x}", self.value.into(), digits = self.digits())
+    }
+}
+
+impl<W: Copy + Into<u128>> fmt::UpperHex for Checksum<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        write!(f, "{:0digits$X}", self.value.into(), digits = self.digits())
+    }
+}
+
+impl<W: Copy + Into<u128>> fmt::Debug for Checksum<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Checksum")
+            .field("value", &format_args!("{self:#x}"))
+            .field("width", &self.width)
+            .finish()
+    }
+}
+
+impl Deref for ChecksumBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.bytes[..self.len as usize]
+    }
+}
+
+impl AsRef<[u8]> for ChecksumBytes {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl fmt::Debug for ChecksumBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
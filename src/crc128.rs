@@ -1,8 +1,17 @@
-use crate::table::crc128_table;
-use crate::util::crc128;
+#[cfg(feature = "alloc")]
+use crate::table::{boxed_table, fill_crc128_table};
+use crate::table::{crc128_nibble_table, crc128_table};
+use crate::util::{crc128, mulmod, residue, validate as validate_fields, xpow8n};
 use crate::*;
 use crc_catalog::Algorithm;
 
+/// Number of independent streams `update_table` splits long inputs into.
+const STREAMS: usize = 3;
+
+/// Minimum input length for which splitting into streams pays off the cost of
+/// combining them.
+const MIN_STREAMS_LEN: usize = 4096;
+
 impl<const L: usize> Crc<u128, Table<L>>
 where
     Table<L>: private::Sealed,
@@ -14,16 +23,109 @@ where
         }
     }
 
+    /// Like `new`, but returns an error if `algorithm` is not valid, see [`validate`](crate::validate).
+    pub const fn try_new(algorithm: &'static Algorithm<u128>) -> Result<Self, AlgorithmError> {
+        match validate(algorithm) {
+            Ok(()) => Ok(Self::new(algorithm)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Checks that the checksum of `"123456789"` matches the `check` of the algorithm.
+    pub const fn verify_check(&self) -> Result<(), AlgorithmError> {
+        if self.checksum(b"123456789") == self.algorithm.check {
+            Ok(())
+        } else {
+            Err(AlgorithmError::CheckMismatch)
+        }
+    }
+
     pub const fn checksum(&self, bytes: &[u8]) -> u128 {
         let mut crc = init(self.algorithm, self.algorithm.init);
         crc = self.update(crc, bytes);
         finalize(self.algorithm, crc)
     }
 
+    /// Like `checksum`, but returns a [`Checksum`] that knows the width of the algorithm.
+    pub const fn typed_checksum(&self, bytes: &[u8]) -> Checksum<u128> {
+        Checksum {
+            value: self.checksum(bytes),
+            width: self.algorithm.width,
+        }
+    }
+
+    /// Computes the checksums of several independent messages into `out`.
+    ///
+    /// With a bytewise table, messages are processed in interleaved lanes so that
+    /// the table lookups of different messages can overlap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `inputs` and `out` differ in length.
+    pub const fn checksum_many(&self, inputs: &[&[u8]], out: &mut [u128]) {
+        assert!(inputs.len() == out.len(), "inputs and out differ in length");
+        let mut i = 0;
+        if L == 1 {
+            while i + LANES <= inputs.len() {
+                let crcs = update_lanes(self.algorithm, &self.data, inputs.split_at(i).1);
+                let mut lane = 0;
+                while lane < LANES {
+                    out[i + lane] = finalize(self.algorithm, crcs[lane]);
+                    lane += 1;
+                }
+                i += LANES;
+            }
+        }
+        while i < inputs.len() {
+            out[i] = self.checksum(inputs[i]);
+            i += 1;
+        }
+    }
+
     const fn update(&self, crc: u128, bytes: &[u8]) -> u128 {
         update_table(crc, self.algorithm, &self.data, bytes)
     }
 
+    /// Updates the checksum `crc` of a `len`-byte message after the bytes at `offset` have
+    /// been changed from `old` to `new`, without reprocessing the rest of the message.
+    ///
+    /// The cost is proportional to the length of the edit and the logarithm of `len`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `old` and `new` differ in length or the edit extends past `len`.
+    pub const fn patch(
+        &self,
+        crc: u128,
+        len: usize,
+        offset: usize,
+        old: &[u8],
+        new: &[u8],
+    ) -> u128 {
+        assert!(old.len() == new.len(), "old and new bytes differ in length");
+        assert!(
+            offset <= len && old.len() <= len - offset,
+            "edit extends past message"
+        );
+
+        // CRCs are affine, so the checksum changes by the CRC of the XOR difference
+        // computed with zero init and xorout, followed by the unchanged trailing bytes.
+        let mut diff = 0;
+        let mut buf = [0u8; 64];
+        let mut i = 0;
+        while i < old.len() {
+            let mut n = 0;
+            while n < buf.len() && i < old.len() {
+                buf[n] = old[i] ^ new[i];
+                n += 1;
+                i += 1;
+            }
+            diff = self.update(diff, buf.split_at(n).0);
+        }
+        diff = shift(self.algorithm, diff, len - offset - old.len());
+        crc ^ finalize(self.algorithm, diff) ^ self.algorithm.xorout
+    }
+
     pub const fn digest(&self) -> Digest<'_, u128, Table<L>> {
         self.digest_with_initial(self.algorithm.init)
     }
@@ -33,6 +135,10 @@ where
     /// This overrides the initial value specified by the algorithm.
     /// The effects of the algorithm's properties `refin` and `width`
     /// are applied to the custom initial value.
+    ///
+    /// The initial value is in the direct form of `Algorithm::init`, see
+    /// [`Poly::direct_init`](crate::poly::Poly::direct_init) to convert the initial
+    /// register of an augmented algorithm.
     pub const fn digest_with_initial(&self, initial: u128) -> Digest<'_, u128, Table<L>> {
         let value = init(self.algorithm, initial);
         Digest::new(self, value)
@@ -55,9 +161,350 @@ where
         self.value = self.crc.update(self.value, bytes);
     }
 
+    /// Updates the digest with each of `slices` in order, as if they were contiguous.
+    pub const fn update_slices(&mut self, slices: &[&[u8]]) {
+        let mut i = 0;
+        while i < slices.len() {
+            self.update(slices[i]);
+            i += 1;
+        }
+    }
+
+    /// Updates the digest with each of `slices` in order, as if they were contiguous.
+    #[cfg(feature = "std")]
+    pub fn update_vectored(&mut self, slices: &[std::io::IoSlice<'_>]) {
+        for slice in slices {
+            self.update(slice);
+        }
+    }
+
+    /// Updates the digest with the bytes of `value` in the given byte order.
+    pub const fn update_u16(&mut self, value: u16, endian: Endian) {
+        match endian {
+            Endian::Big => self.update(&value.to_be_bytes()),
+            Endian::Little => self.update(&value.to_le_bytes()),
+        }
+    }
+
+    /// Updates the digest with the bytes of `value` in the given byte order.
+    pub const fn update_u32(&mut self, value: u32, endian: Endian) {
+        match endian {
+            Endian::Big => self.update(&value.to_be_bytes()),
+            Endian::Little => self.update(&value.to_le_bytes()),
+        }
+    }
+
+    /// Updates the digest with the bytes of `value` in the given byte order.
+    pub const fn update_u64(&mut self, value: u64, endian: Endian) {
+        match endian {
+            Endian::Big => self.update(&value.to_be_bytes()),
+            Endian::Little => self.update(&value.to_le_bytes()),
+        }
+    }
+
+    /// Updates the digest with `words`, each in little-endian byte order.
+    pub const fn update_words_le(&mut self, words: &[u32]) {
+        self.update_words(words, Endian::Little);
+    }
+
+    /// Updates the digest with `words`, each in big-endian byte order.
+    pub const fn update_words_be(&mut self, words: &[u32]) {
+        self.update_words(words, Endian::Big);
+    }
+
+    const fn update_words(&mut self, words: &[u32], endian: Endian) {
+        let mut buf = [0u8; 64];
+        let mut i = 0;
+        while i < words.len() {
+            let mut n = 0;
+            while n < buf.len() && i < words.len() {
+                let bytes = match endian {
+                    Endian::Big => words[i].to_be_bytes(),
+                    Endian::Little => words[i].to_le_bytes(),
+                };
+                buf[n] = bytes[0];
+                buf[n + 1] = bytes[1];
+                buf[n + 2] = bytes[2];
+                buf[n + 3] = bytes[3];
+                n += 4;
+                i += 1;
+            }
+            self.update(buf.split_at(n).0);
+        }
+    }
+
+    /// Updates the digest with the bytes yielded by `bytes`.
+    pub fn update_iter(&mut self, bytes: impl IntoIterator<Item = u8>) {
+        let mut buf = [0u8; 64];
+        let mut n = 0;
+        for byte in bytes {
+            buf[n] = byte;
+            n += 1;
+            if n == buf.len() {
+                self.update(&buf);
+                n = 0;
+            }
+        }
+        self.update(&buf[..n]);
+    }
+
     pub const fn finalize(self) -> u128 {
         finalize(self.crc.algorithm, self.value)
     }
+
+    /// Like `finalize`, but returns a [`Checksum`] that knows the width of the algorithm.
+    pub const fn finalize_typed(self) -> Checksum<u128> {
+        Checksum {
+            value: finalize(self.crc.algorithm, self.value),
+            width: self.crc.algorithm.width,
+        }
+    }
+}
+
+impl<const L: usize> Extend<u8> for Digest<'_, u128, Table<L>>
+where
+    Table<L>: private::Sealed,
+{
+    fn extend<T: IntoIterator<Item = u8>>(&mut self, bytes: T) {
+        self.update_iter(bytes);
+    }
+}
+
+impl<'b, const L: usize> Extend<&'b u8> for Digest<'_, u128, Table<L>>
+where
+    Table<L>: private::Sealed,
+{
+    fn extend<T: IntoIterator<Item = &'b u8>>(&mut self, bytes: T) {
+        self.update_iter(bytes.into_iter().copied());
+    }
+}
+
+impl AlgorithmBuilder<u128> {
+    /// Starts an algorithm with the given `width` and `poly`, a zero `init` and `xorout`,
+    /// and no reflection.
+    pub const fn new(width: u8, poly: u128) -> Self {
+        Self {
+            algorithm: Algorithm {
+                width,
+                poly,
+                init: 0,
+                refin: false,
+                refout: false,
+                xorout: 0,
+                check: 0,
+                residue: 0,
+            },
+        }
+    }
+
+    pub const fn init(mut self, init: u128) -> Self {
+        self.algorithm.init = init;
+        self
+    }
+
+    pub const fn refin(mut self, refin: bool) -> Self {
+        self.algorithm.refin = refin;
+        self
+    }
+
+    pub const fn refout(mut self, refout: bool) -> Self {
+        self.algorithm.refout = refout;
+        self
+    }
+
+    pub const fn xorout(mut self, xorout: u128) -> Self {
+        self.algorithm.xorout = xorout;
+        self
+    }
+
+    /// Computes `check` and `residue`, and returns the algorithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the algorithm is not valid, see [`validate`](crate::validate).
+    pub const fn build(self) -> Algorithm<u128> {
+        let mut algorithm = self.algorithm;
+        assert!(validate(&algorithm).is_ok(), "invalid algorithm");
+        let crc = init(&algorithm, algorithm.init);
+        let crc = update_table::<0>(crc, &algorithm, &[], b"123456789");
+        algorithm.check = finalize(&algorithm, crc);
+        algorithm.residue = residue(
+            algorithm.width,
+            algorithm.poly,
+            algorithm.xorout,
+            algorithm.refin,
+            algorithm.refout,
+        );
+        algorithm
+    }
+}
+
+impl private::Register for u128 {
+    fn init(algorithm: &Algorithm<u128>, initial: u128) -> u128 {
+        init(algorithm, initial)
+    }
+
+    fn finalize(algorithm: &Algorithm<u128>, crc: u128) -> u128 {
+        finalize(algorithm, crc)
+    }
+
+    fn shift(algorithm: &Algorithm<u128>, crc: u128, n: usize) -> u128 {
+        shift(algorithm, crc, n)
+    }
+
+    fn validate(algorithm: &Algorithm<u128>) -> Result<(), AlgorithmError> {
+        validate(algorithm)
+    }
+
+    fn checksum(algorithm: &Algorithm<u128>, bytes: &[u8]) -> u128 {
+        let crc = init(algorithm, algorithm.init);
+        finalize(algorithm, update_table::<0>(crc, algorithm, &[], bytes))
+    }
+}
+
+impl<const L: usize> CrcTable<u128, L> {
+    pub const fn new(algorithm: &Algorithm<u128>) -> Self {
+        Self {
+            width: algorithm.width,
+            poly: algorithm.poly,
+            refin: algorithm.refin,
+            table: crc128_table(algorithm.width, algorithm.poly, algorithm.refin),
+        }
+    }
+
+    /// Like `new`, but returns an error if `algorithm` is not valid, see [`validate`](crate::validate).
+    pub const fn try_new(algorithm: &Algorithm<u128>) -> Result<Self, AlgorithmError> {
+        match validate(algorithm) {
+            Ok(()) => Ok(Self::new(algorithm)),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub const fn table(&self) -> &[[u128; 256]; L] {
+        &self.table
+    }
+}
+
+impl<const L: usize> Crc<u128, SharedTable<L>> {
+    /// Construct a `Crc` that uses the shared lookup `table`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `table` was built for a different `width`, `poly` or `refin`.
+    pub const fn with_table(
+        algorithm: &'static Algorithm<u128>,
+        table: &'static CrcTable<u128, L>,
+    ) -> Self {
+        assert!(
+            table.width == algorithm.width
+                && table.poly == algorithm.poly
+                && table.refin == algorithm.refin,
+            "table does not match algorithm"
+        );
+        Self {
+            algorithm,
+            data: table,
+        }
+    }
+
+    /// Like `with_table`, but returns an error if `algorithm` is not valid, see
+    /// [`validate`](crate::validate), or if `table` does not match it.
+    pub const fn try_with_table(
+        algorithm: &'static Algorithm<u128>,
+        table: &'static CrcTable<u128, L>,
+    ) -> Result<Self, AlgorithmError> {
+        if let Err(err) = validate(algorithm) {
+            return Err(err);
+        }
+        if table.width != algorithm.width
+            || table.poly != algorithm.poly
+            || table.refin != algorithm.refin
+        {
+            return Err(AlgorithmError::TableMismatch);
+        }
+        Ok(Self::with_table(algorithm, table))
+    }
+}
+
+impl<const L: usize> private::Kernel<u128> for SharedTable<L> {
+    fn update(
+        crc: u128,
+        algorithm: &Algorithm<u128>,
+        table: &Self::Data<u128>,
+        bytes: &[u8],
+    ) -> u128 {
+        update_table(crc, algorithm, &table.table, bytes)
+    }
+}
+
+impl Crc<u128, NibbleTable> {
+    pub const fn new(algorithm: &'static Algorithm<u128>) -> Self {
+        Self {
+            algorithm,
+            data: crc128_nibble_table(algorithm.width, algorithm.poly, algorithm.refin),
+        }
+    }
+
+    /// Like `new`, but returns an error if `algorithm` is not valid, see [`validate`](crate::validate).
+    pub const fn try_new(algorithm: &'static Algorithm<u128>) -> Result<Self, AlgorithmError> {
+        match validate(algorithm) {
+            Ok(()) => Ok(Self::new(algorithm)),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub const fn table(&self) -> &[u128; 16] {
+        &self.data
+    }
+}
+
+impl private::Kernel<u128> for NibbleTable {
+    fn update(crc: u128, algorithm: &Algorithm<u128>, table: &[u128; 16], bytes: &[u8]) -> u128 {
+        update_nibbles(crc, algorithm.refin, table, bytes)
+    }
+}
+
+impl<const L: usize> private::Build<u128> for Table<L> {
+    fn build(algorithm: &Algorithm<u128>) -> Self::Data<u128> {
+        crc128_table(algorithm.width, algorithm.poly, algorithm.refin)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const L: usize> private::Build<u128> for BoxedTable<L> {
+    fn build(algorithm: &Algorithm<u128>) -> Self::Data<u128> {
+        let mut table = boxed_table();
+        fill_crc128_table(
+            &mut *table,
+            algorithm.width,
+            algorithm.poly,
+            algorithm.refin,
+        );
+        table
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const L: usize> private::Kernel<u128> for BoxedTable<L> {
+    fn update(
+        crc: u128,
+        algorithm: &Algorithm<u128>,
+        table: &Self::Data<u128>,
+        bytes: &[u8],
+    ) -> u128 {
+        update_table(crc, algorithm, table, bytes)
+    }
+}
+
+const fn validate(algorithm: &Algorithm<u128>) -> Result<(), AlgorithmError> {
+    let values = [
+        algorithm.poly,
+        algorithm.init,
+        algorithm.xorout,
+        algorithm.check,
+        algorithm.residue,
+    ];
+    validate_fields(u128::BITS, algorithm.width, values)
 }
 
 const fn init(algorithm: &Algorithm<u128>, initial: u128) -> u128 {
@@ -78,6 +525,23 @@ const fn finalize(algorithm: &Algorithm<u128>, mut crc: u128) -> u128 {
     crc ^ algorithm.xorout
 }
 
+/// Advances the register `crc` through `n` zero bytes.
+const fn shift(algorithm: &Algorithm<u128>, crc: u128, n: usize) -> u128 {
+    let power = xpow8n(n, algorithm.poly, algorithm.width);
+    multiply(algorithm, crc, power)
+}
+
+/// Multiplies the register `crc` by `power` modulo the polynomial.
+const fn multiply(algorithm: &Algorithm<u128>, crc: u128, power: u128) -> u128 {
+    let value = if algorithm.refin {
+        crc.reverse_bits() >> (128u8 - algorithm.width)
+    } else {
+        crc >> (128u8 - algorithm.width)
+    };
+    let value = mulmod(value, power, algorithm.poly, algorithm.width);
+    init(algorithm, value)
+}
+
 const fn update_table<const L: usize>(
     mut crc: u128,
     algorithm: &Algorithm<u128>,
@@ -88,85 +552,32 @@ const fn update_table<const L: usize>(
     let mut i = 0;
     let reflect = algorithm.refin;
 
-    // Process 16 bytes at a time when L=16
-    if L == 16 {
-        while i + 16 <= len {
-            if reflect {
-                // XOR the first 16 bytes with the current CRC value
-                let current0 = bytes[i] ^ (crc as u8);
-                let current1 = bytes[i + 1] ^ ((crc >> 8) as u8);
-                let current2 = bytes[i + 2] ^ ((crc >> 16) as u8);
-                let current3 = bytes[i + 3] ^ ((crc >> 24) as u8);
-                let current4 = bytes[i + 4] ^ ((crc >> 32) as u8);
-                let current5 = bytes[i + 5] ^ ((crc >> 40) as u8);
-                let current6 = bytes[i + 6] ^ ((crc >> 48) as u8);
-                let current7 = bytes[i + 7] ^ ((crc >> 56) as u8);
-                let current8 = bytes[i + 8] ^ ((crc >> 64) as u8);
-                let current9 = bytes[i + 9] ^ ((crc >> 72) as u8);
-                let current10 = bytes[i + 10] ^ ((crc >> 80) as u8);
-                let current11 = bytes[i + 11] ^ ((crc >> 88) as u8);
-                let current12 = bytes[i + 12] ^ ((crc >> 96) as u8);
-                let current13 = bytes[i + 13] ^ ((crc >> 104) as u8);
-                let current14 = bytes[i + 14] ^ ((crc >> 112) as u8);
-                let current15 = bytes[i + 15] ^ ((crc >> 120) as u8);
-
-                crc = table[0][current15 as usize]
-                    ^ table[1][current14 as usize]
-                    ^ table[2][current13 as usize]
-                    ^ table[3][current12 as usize]
-                    ^ table[4][current11 as usize]
-                    ^ table[5][current10 as usize]
-                    ^ table[6][current9 as usize]
-                    ^ table[7][current8 as usize]
-                    ^ table[8][current7 as usize]
-                    ^ table[9][current6 as usize]
-                    ^ table[10][current5 as usize]
-                    ^ table[11][current4 as usize]
-                    ^ table[12][current3 as usize]
-                    ^ table[13][current2 as usize]
-                    ^ table[14][current1 as usize]
-                    ^ table[15][current0 as usize];
-            } else {
-                // For non-reflected CRC128
-                let current0 = bytes[i] ^ ((crc >> 120) as u8);
-                let current1 = bytes[i + 1] ^ ((crc >> 112) as u8);
-                let current2 = bytes[i + 2] ^ ((crc >> 104) as u8);
-                let current3 = bytes[i + 3] ^ ((crc >> 96) as u8);
-                let current4 = bytes[i + 4] ^ ((crc >> 88) as u8);
-                let current5 = bytes[i + 5] ^ ((crc >> 80) as u8);
-                let current6 = bytes[i + 6] ^ ((crc >> 72) as u8);
-                let current7 = bytes[i + 7] ^ ((crc >> 64) as u8);
-                let current8 = bytes[i + 8] ^ ((crc >> 56) as u8);
-                let current9 = bytes[i + 9] ^ ((crc >> 48) as u8);
-                let current10 = bytes[i + 10] ^ ((crc >> 40) as u8);
-                let current11 = bytes[i + 11] ^ ((crc >> 32) as u8);
-                let current12 = bytes[i + 12] ^ ((crc >> 24) as u8);
-                let current13 = bytes[i + 13] ^ ((crc >> 16) as u8);
-                let current14 = bytes[i + 14] ^ ((crc >> 8) as u8);
-                let current15 = bytes[i + 15] ^ (crc as u8);
-
-                crc = table[0][current15 as usize]
-                    ^ table[1][current14 as usize]
-                    ^ table[2][current13 as usize]
-                    ^ table[3][current12 as usize]
-                    ^ table[4][current11 as usize]
-                    ^ table[5][current10 as usize]
-                    ^ table[6][current9 as usize]
-                    ^ table[7][current8 as usize]
-                    ^ table[8][current7 as usize]
-                    ^ table[9][current6 as usize]
-                    ^ table[10][current5 as usize]
-                    ^ table[11][current4 as usize]
-                    ^ table[12][current3 as usize]
-                    ^ table[13][current2 as usize]
-                    ^ table[14][current1 as usize]
-                    ^ table[15][current0 as usize];
-            }
-            i += 16;
+    // With a 128-bit register, (up to) 16 lookups of each slice depend on the previous
+    // one, which makes a single stream latency bound. Long inputs are therefore split
+    // into independent streams processed in lockstep, which are combined afterwards.
+    if L > 1 && len >= MIN_STREAMS_LEN {
+        let n = len / STREAMS / L * L;
+        let (mut crc1, mut crc2) = (0, 0);
+        while i < n {
+            crc = update_slice(crc, reflect, table, bytes.split_at(i).1);
+            crc1 = update_slice(crc1, reflect, table, bytes.split_at(n + i).1);
+            crc2 = update_slice(crc2, reflect, table, bytes.split_at(2 * n + i).1);
+            i += L;
         }
+        let power = xpow8n(n, algorithm.poly, algorithm.width);
+        crc = multiply(algorithm, multiply(algorithm, crc, power) ^ crc1, power) ^ crc2;
+        i = STREAMS * n;
     }
 
-    // Process remaining bytes one at a time using the table (for L=1 and L=16)
+    // Process L bytes at a time using the slicing tables
+    if L > 1 {
+        while i + L <= len {
+            crc = update_slice(crc, reflect, table, bytes.split_at(i).1);
+            i += L;
+        }
+    }
+
+    // Process remaining bytes one at a time using the first table
     if L > 0 {
         if reflect {
             while i < len {
@@ -208,6 +619,122 @@ const fn update_table<const L: usize>(
     crc
 }
 
+/// Processes the first `L` bytes of `bytes` using the slicing tables. The first (up to)
+/// 16 bytes are combined with the current CRC value, whose remaining bytes are carried
+/// over. The lookups that don't depend on the CRC value come first to shorten the
+/// dependency chain between slices.
+const fn update_slice<const L: usize>(
+    crc: u128,
+    reflect: bool,
+    table: &[[u128; 256]; L],
+    bytes: &[u8],
+) -> u128 {
+    let chunk = bytes.split_at(L).0;
+    let mut next = 0;
+    let mut j = L;
+    while j > 16 {
+        j -= 1;
+        next ^= table[L - 1 - j][chunk[j] as usize];
+    }
+
+    let mut word = [0u8; 16];
+    let mut k = 0;
+    while k < j {
+        word[k] = chunk[k];
+        k += 1;
+    }
+    // The bytes are numbered in the order they are processed, i.e. from the least
+    // significant byte of a reflected register and the most significant otherwise
+    let word = if reflect {
+        u128::from_le_bytes(word) ^ crc
+    } else {
+        u128::from_be_bytes(word) ^ crc
+    };
+    while j > 0 {
+        j -= 1;
+        let byte = if reflect {
+            word >> (8 * j)
+        } else {
+            word >> (120 - 8 * j)
+        };
+        next ^= table[L - 1 - j][byte as u8 as usize];
+    }
+
+    if L >= 16 {
+        next
+    } else if reflect {
+        next ^ (crc >> (8 * L))
+    } else {
+        next ^ (crc << (8 * L))
+    }
+}
+
+/// Updates the first `LANES` messages of `inputs` in lockstep, returning the
+/// unfinalized registers.
+const fn update_lanes<const L: usize>(
+    algorithm: &Algorithm<u128>,
+    table: &[[u128; 256]; L],
+    inputs: &[&[u8]],
+) -> [u128; LANES] {
+    let mut crcs = [init(algorithm, algorithm.init); LANES];
+    let reflect = algorithm.refin;
+
+    let mut len = inputs[0].len();
+    let mut lane = 1;
+    while lane < LANES {
+        if inputs[lane].len() < len {
+            len = inputs[lane].len();
+        }
+        lane += 1;
+    }
+
+    let mut k = 0;
+    while k < len {
+        let mut lane = 0;
+        if reflect {
+            while lane < LANES {
+                let index = ((crcs[lane] ^ inputs[lane][k] as u128) & 0xFF) as usize;
+                crcs[lane] = table[0][index] ^ (crcs[lane] >> 8);
+                lane += 1;
+            }
+        } else {
+            while lane < LANES {
+                let index = (((crcs[lane] >> 120) ^ inputs[lane][k] as u128) & 0xFF) as usize;
+                crcs[lane] = table[0][index] ^ (crcs[lane] << 8);
+                lane += 1;
+            }
+        }
+        k += 1;
+    }
+
+    let mut lane = 0;
+    while lane < LANES {
+        crcs[lane] = update_table(crcs[lane], algorithm, table, inputs[lane].split_at(len).1);
+        lane += 1;
+    }
+    crcs
+}
+
+const fn update_nibbles(mut crc: u128, reflect: bool, table: &[u128; 16], bytes: &[u8]) -> u128 {
+    let mut i = 0;
+    if reflect {
+        while i < bytes.len() {
+            crc ^= bytes[i] as u128;
+            crc = (crc >> 4) ^ table[(crc & 0xF) as usize];
+            crc = (crc >> 4) ^ table[(crc & 0xF) as usize];
+            i += 1;
+        }
+    } else {
+        while i < bytes.len() {
+            crc ^= (bytes[i] as u128) << 120;
+            crc = (crc << 4) ^ table[(crc >> 124) as usize];
+            crc = (crc << 4) ^ table[(crc >> 124) as usize];
+            i += 1;
+        }
+    }
+    crc
+}
+
 #[cfg(test)]
 mod test {
     use crate::*;
@@ -245,6 +772,20 @@ mod test {
                 let crc_nolookup = Crc::<u128, NoTable>::new(alg);
                 let expected = Crc::<u128, Table<1>>::new(alg).checksum(data.as_bytes());
 
+                // Check the other lane counts, including ones wider than the CRC
+                let bytes = data.as_bytes();
+                assert_eq!(Crc::<u128, Table<2>>::new(alg).checksum(bytes), expected);
+                assert_eq!(Crc::<u128, Table<3>>::new(alg).checksum(bytes), expected);
+                assert_eq!(Crc::<u128, Table<4>>::new(alg).checksum(bytes), expected);
+                assert_eq!(Crc::<u128, Table<8>>::new(alg).checksum(bytes), expected);
+                assert_eq!(Crc::<u128, Table<32>>::new(alg).checksum(bytes), expected);
+                assert_eq!(Crc::<u128, NibbleTable>::new(alg).checksum(bytes), expected);
+                #[cfg(feature = "alloc")]
+                assert_eq!(
+                    Crc::<u128, BoxedTable<16>>::new(alg).checksum(bytes),
+                    expected
+                );
+
                 // Check that doing all at once works as expected
                 assert_eq!(crc_slice16.checksum(data.as_bytes()), expected);
                 assert_eq!(crc_nolookup.checksum(data.as_bytes()), expected);
@@ -274,4 +815,39 @@ mod test {
             }
         }
     }
+
+    /// Long inputs are split into several streams, see `update_table`
+    #[test]
+    fn streams() {
+        let mut data = [0u8; 3 * super::MIN_STREAMS_LEN + 21];
+        for (i, byte) in data.iter_mut().enumerate() {
+            *byte = (i * 7 + i / 251) as u8;
+        }
+
+        pub const CRC_82_DARC_NONREFLEX: Algorithm<u128> = Algorithm {
+            width: 82,
+            poly: 0x0308c0111011401440411,
+            init: 0x000000000000000000000,
+            refin: false,
+            refout: true,
+            xorout: 0x000000000000000000000,
+            check: 0x09ea83f625023801fd612,
+            residue: 0x000000000000000000000,
+        };
+
+        for alg in [&CRC_82_DARC, &CRC_82_DARC_NONREFLEX] {
+            for len in [
+                super::MIN_STREAMS_LEN - 1,
+                super::MIN_STREAMS_LEN + 5,
+                data.len(),
+            ] {
+                let bytes = &data[..len];
+                let expected = Crc::<u128, Table<1>>::new(alg).checksum(bytes);
+                assert_eq!(Crc::<u128, Table<2>>::new(alg).checksum(bytes), expected);
+                assert_eq!(Crc::<u128, Table<3>>::new(alg).checksum(bytes), expected);
+                assert_eq!(Crc::<u128, Table<16>>::new(alg).checksum(bytes), expected);
+                assert_eq!(Crc::<u128, Table<32>>::new(alg).checksum(bytes), expected);
+            }
+        }
+    }
 }
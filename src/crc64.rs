@@ -1,5 +1,9 @@
-use crate::table::crc64_table;
-use crate::util::crc64;
+#[cfg(feature = "alloc")]
+use crate::table::{boxed_table, fill_crc64_table};
+use crate::table::{crc64_braid_table, crc64_nibble_table, crc64_table};
+use crate::util::{
+    barrett_constants, crc64, mulmod, residue, update_barrett, validate as validate_fields, xpow8n,
+};
 use crate::*;
 use crc_catalog::Algorithm;
 
@@ -14,16 +18,102 @@ where
         }
     }
 
+    /// Like `new`, but returns an error if `algorithm` is not valid, see [`validate`](crate::validate).
+    pub const fn try_new(algorithm: &'static Algorithm<u64>) -> Result<Self, AlgorithmError> {
+        match validate(algorithm) {
+            Ok(()) => Ok(Self::new(algorithm)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Checks that the checksum of `"123456789"` matches the `check` of the algorithm.
+    pub const fn verify_check(&self) -> Result<(), AlgorithmError> {
+        if self.checksum(b"123456789") == self.algorithm.check {
+            Ok(())
+        } else {
+            Err(AlgorithmError::CheckMismatch)
+        }
+    }
+
     pub const fn checksum(&self, bytes: &[u8]) -> u64 {
         let mut crc = init(self.algorithm, self.algorithm.init);
         crc = self.update(crc, bytes);
         finalize(self.algorithm, crc)
     }
 
+    /// Like `checksum`, but returns a [`Checksum`] that knows the width of the algorithm.
+    pub const fn typed_checksum(&self, bytes: &[u8]) -> Checksum<u64> {
+        Checksum {
+            value: self.checksum(bytes),
+            width: self.algorithm.width,
+        }
+    }
+
+    /// Computes the checksums of several independent messages into `out`.
+    ///
+    /// With a bytewise table, messages are processed in interleaved lanes so that
+    /// the table lookups of different messages can overlap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `inputs` and `out` differ in length.
+    pub const fn checksum_many(&self, inputs: &[&[u8]], out: &mut [u64]) {
+        assert!(inputs.len() == out.len(), "inputs and out differ in length");
+        let mut i = 0;
+        if L == 1 {
+            while i + LANES <= inputs.len() {
+                let crcs = update_lanes(self.algorithm, &self.data, inputs.split_at(i).1);
+                let mut lane = 0;
+                while lane < LANES {
+                    out[i + lane] = finalize(self.algorithm, crcs[lane]);
+                    lane += 1;
+                }
+                i += LANES;
+            }
+        }
+        while i < inputs.len() {
+            out[i] = self.checksum(inputs[i]);
+            i += 1;
+        }
+    }
+
     const fn update(&self, crc: u64, bytes: &[u8]) -> u64 {
         update_table(crc, self.algorithm, &self.data, bytes)
     }
 
+    /// Updates the checksum `crc` of a `len`-byte message after the bytes at `offset` have
+    /// been changed from `old` to `new`, without reprocessing the rest of the message.
+    ///
+    /// The cost is proportional to the length of the edit and the logarithm of `len`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `old` and `new` differ in length or the edit extends past `len`.
+    pub const fn patch(&self, crc: u64, len: usize, offset: usize, old: &[u8], new: &[u8]) -> u64 {
+        assert!(old.len() == new.len(), "old and new bytes differ in length");
+        assert!(
+            offset <= len && old.len() <= len - offset,
+            "edit extends past message"
+        );
+
+        // CRCs are affine, so the checksum changes by the CRC of the XOR difference
+        // computed with zero init and xorout, followed by the unchanged trailing bytes.
+        let mut diff = 0;
+        let mut buf = [0u8; 64];
+        let mut i = 0;
+        while i < old.len() {
+            let mut n = 0;
+            while n < buf.len() && i < old.len() {
+                buf[n] = old[i] ^ new[i];
+                n += 1;
+                i += 1;
+            }
+            diff = self.update(diff, buf.split_at(n).0);
+        }
+        diff = shift(self.algorithm, diff, len - offset - old.len());
+        crc ^ finalize(self.algorithm, diff) ^ self.algorithm.xorout
+    }
+
     pub const fn digest(&self) -> Digest<'_, u64, Table<L>> {
         self.digest_with_initial(self.algorithm.init)
     }
@@ -33,6 +123,10 @@ where
     /// This overrides the initial value specified by the algorithm.
     /// The effects of the algorithm's properties `refin` and `width`
     /// are applied to the custom initial value.
+    ///
+    /// The initial value is in the direct form of `Algorithm::init`, see
+    /// [`Poly::direct_init`](crate::poly::Poly::direct_init) to convert the initial
+    /// register of an augmented algorithm.
     pub const fn digest_with_initial(&self, initial: u64) -> Digest<'_, u64, Table<L>> {
         let value = init(self.algorithm, initial);
         Digest::new(self, value)
@@ -55,9 +149,397 @@ where
         self.value = self.crc.update(self.value, bytes);
     }
 
+    /// Updates the digest with each of `slices` in order, as if they were contiguous.
+    pub const fn update_slices(&mut self, slices: &[&[u8]]) {
+        let mut i = 0;
+        while i < slices.len() {
+            self.update(slices[i]);
+            i += 1;
+        }
+    }
+
+    /// Updates the digest with each of `slices` in order, as if they were contiguous.
+    #[cfg(feature = "std")]
+    pub fn update_vectored(&mut self, slices: &[std::io::IoSlice<'_>]) {
+        for slice in slices {
+            self.update(slice);
+        }
+    }
+
+    /// Updates the digest with the bytes of `value` in the given byte order.
+    pub const fn update_u16(&mut self, value: u16, endian: Endian) {
+        match endian {
+            Endian::Big => self.update(&value.to_be_bytes()),
+            Endian::Little => self.update(&value.to_le_bytes()),
+        }
+    }
+
+    /// Updates the digest with the bytes of `value` in the given byte order.
+    pub const fn update_u32(&mut self, value: u32, endian: Endian) {
+        match endian {
+            Endian::Big => self.update(&value.to_be_bytes()),
+            Endian::Little => self.update(&value.to_le_bytes()),
+        }
+    }
+
+    /// Updates the digest with the bytes of `value` in the given byte order.
+    pub const fn update_u64(&mut self, value: u64, endian: Endian) {
+        match endian {
+            Endian::Big => self.update(&value.to_be_bytes()),
+            Endian::Little => self.update(&value.to_le_bytes()),
+        }
+    }
+
+    /// Updates the digest with `words`, each in little-endian byte order.
+    pub const fn update_words_le(&mut self, words: &[u32]) {
+        self.update_words(words, Endian::Little);
+    }
+
+    /// Updates the digest with `words`, each in big-endian byte order.
+    pub const fn update_words_be(&mut self, words: &[u32]) {
+        self.update_words(words, Endian::Big);
+    }
+
+    const fn update_words(&mut self, words: &[u32], endian: Endian) {
+        let mut buf = [0u8; 64];
+        let mut i = 0;
+        while i < words.len() {
+            let mut n = 0;
+            while n < buf.len() && i < words.len() {
+                let bytes = match endian {
+                    Endian::Big => words[i].to_be_bytes(),
+                    Endian::Little => words[i].to_le_bytes(),
+                };
+                buf[n] = bytes[0];
+                buf[n + 1] = bytes[1];
+                buf[n + 2] = bytes[2];
+                buf[n + 3] = bytes[3];
+                n += 4;
+                i += 1;
+            }
+            self.update(buf.split_at(n).0);
+        }
+    }
+
+    /// Updates the digest with the bytes yielded by `bytes`.
+    pub fn update_iter(&mut self, bytes: impl IntoIterator<Item = u8>) {
+        let mut buf = [0u8; 64];
+        let mut n = 0;
+        for byte in bytes {
+            buf[n] = byte;
+            n += 1;
+            if n == buf.len() {
+                self.update(&buf);
+                n = 0;
+            }
+        }
+        self.update(&buf[..n]);
+    }
+
     pub const fn finalize(self) -> u64 {
         finalize(self.crc.algorithm, self.value)
     }
+
+    /// Like `finalize`, but returns a [`Checksum`] that knows the width of the algorithm.
+    pub const fn finalize_typed(self) -> Checksum<u64> {
+        Checksum {
+            value: finalize(self.crc.algorithm, self.value),
+            width: self.crc.algorithm.width,
+        }
+    }
+}
+
+impl<const L: usize> Extend<u8> for Digest<'_, u64, Table<L>>
+where
+    Table<L>: private::Sealed,
+{
+    fn extend<T: IntoIterator<Item = u8>>(&mut self, bytes: T) {
+        self.update_iter(bytes);
+    }
+}
+
+impl<'b, const L: usize> Extend<&'b u8> for Digest<'_, u64, Table<L>>
+where
+    Table<L>: private::Sealed,
+{
+    fn extend<T: IntoIterator<Item = &'b u8>>(&mut self, bytes: T) {
+        self.update_iter(bytes.into_iter().copied());
+    }
+}
+
+impl AlgorithmBuilder<u64> {
+    /// Starts an algorithm with the given `width` and `poly`, a zero `init` and `xorout`,
+    /// and no reflection.
+    pub const fn new(width: u8, poly: u64) -> Self {
+        Self {
+            algorithm: Algorithm {
+                width,
+                poly,
+                init: 0,
+                refin: false,
+                refout: false,
+                xorout: 0,
+                check: 0,
+                residue: 0,
+            },
+        }
+    }
+
+    pub const fn init(mut self, init: u64) -> Self {
+        self.algorithm.init = init;
+        self
+    }
+
+    pub const fn refin(mut self, refin: bool) -> Self {
+        self.algorithm.refin = refin;
+        self
+    }
+
+    pub const fn refout(mut self, refout: bool) -> Self {
+        self.algorithm.refout = refout;
+        self
+    }
+
+    pub const fn xorout(mut self, xorout: u64) -> Self {
+        self.algorithm.xorout = xorout;
+        self
+    }
+
+    /// Computes `check` and `residue`, and returns the algorithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the algorithm is not valid, see [`validate`](crate::validate).
+    pub const fn build(self) -> Algorithm<u64> {
+        let mut algorithm = self.algorithm;
+        assert!(validate(&algorithm).is_ok(), "invalid algorithm");
+        let crc = init(&algorithm, algorithm.init);
+        let crc = update_table::<0>(crc, &algorithm, &[], b"123456789");
+        algorithm.check = finalize(&algorithm, crc);
+        algorithm.residue = residue(
+            algorithm.width,
+            algorithm.poly as u128,
+            algorithm.xorout as u128,
+            algorithm.refin,
+            algorithm.refout,
+        ) as u64;
+        algorithm
+    }
+}
+
+impl private::Register for u64 {
+    fn init(algorithm: &Algorithm<u64>, initial: u64) -> u64 {
+        init(algorithm, initial)
+    }
+
+    fn finalize(algorithm: &Algorithm<u64>, crc: u64) -> u64 {
+        finalize(algorithm, crc)
+    }
+
+    fn shift(algorithm: &Algorithm<u64>, crc: u64, n: usize) -> u64 {
+        shift(algorithm, crc, n)
+    }
+
+    fn validate(algorithm: &Algorithm<u64>) -> Result<(), AlgorithmError> {
+        validate(algorithm)
+    }
+
+    fn checksum(algorithm: &Algorithm<u64>, bytes: &[u8]) -> u64 {
+        let crc = init(algorithm, algorithm.init);
+        finalize(algorithm, update_table::<0>(crc, algorithm, &[], bytes))
+    }
+}
+
+impl<const L: usize> CrcTable<u64, L> {
+    pub const fn new(algorithm: &Algorithm<u64>) -> Self {
+        Self {
+            width: algorithm.width,
+            poly: algorithm.poly,
+            refin: algorithm.refin,
+            table: crc64_table(algorithm.width, algorithm.poly, algorithm.refin),
+        }
+    }
+
+    /// Like `new`, but returns an error if `algorithm` is not valid, see [`validate`](crate::validate).
+    pub const fn try_new(algorithm: &Algorithm<u64>) -> Result<Self, AlgorithmError> {
+        match validate(algorithm) {
+            Ok(()) => Ok(Self::new(algorithm)),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub const fn table(&self) -> &[[u64; 256]; L] {
+        &self.table
+    }
+}
+
+impl<const L: usize> Crc<u64, SharedTable<L>> {
+    /// Construct a `Crc` that uses the shared lookup `table`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `table` was built for a different `width`, `poly` or `refin`.
+    pub const fn with_table(
+        algorithm: &'static Algorithm<u64>,
+        table: &'static CrcTable<u64, L>,
+    ) -> Self {
+        assert!(
+            table.width == algorithm.width
+                && table.poly == algorithm.poly
+                && table.refin == algorithm.refin,
+            "table does not match algorithm"
+        );
+        Self {
+            algorithm,
+            data: table,
+        }
+    }
+
+    /// Like `with_table`, but returns an error if `algorithm` is not valid, see
+    /// [`validate`](crate::validate), or if `table` does not match it.
+    pub const fn try_with_table(
+        algorithm: &'static Algorithm<u64>,
+        table: &'static CrcTable<u64, L>,
+    ) -> Result<Self, AlgorithmError> {
+        if let Err(err) = validate(algorithm) {
+            return Err(err);
+        }
+        if table.width != algorithm.width
+            || table.poly != algorithm.poly
+            || table.refin != algorithm.refin
+        {
+            return Err(AlgorithmError::TableMismatch);
+        }
+        Ok(Self::with_table(algorithm, table))
+    }
+}
+
+impl<const L: usize> private::Kernel<u64> for SharedTable<L> {
+    fn update(crc: u64, algorithm: &Algorithm<u64>, table: &Self::Data<u64>, bytes: &[u8]) -> u64 {
+        update_table(crc, algorithm, &table.table, bytes)
+    }
+}
+
+impl Crc<u64, NibbleTable> {
+    pub const fn new(algorithm: &'static Algorithm<u64>) -> Self {
+        Self {
+            algorithm,
+            data: crc64_nibble_table(algorithm.width, algorithm.poly, algorithm.refin),
+        }
+    }
+
+    /// Like `new`, but returns an error if `algorithm` is not valid, see [`validate`](crate::validate).
+    pub const fn try_new(algorithm: &'static Algorithm<u64>) -> Result<Self, AlgorithmError> {
+        match validate(algorithm) {
+            Ok(()) => Ok(Self::new(algorithm)),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub const fn table(&self) -> &[u64; 16] {
+        &self.data
+    }
+}
+
+impl private::Kernel<u64> for NibbleTable {
+    fn update(crc: u64, algorithm: &Algorithm<u64>, table: &[u64; 16], bytes: &[u8]) -> u64 {
+        update_nibbles(crc, algorithm.refin, table, bytes)
+    }
+}
+
+impl<const N: usize> Crc<u64, Braid<N>> {
+    pub const fn new(algorithm: &'static Algorithm<u64>) -> Self {
+        Self {
+            algorithm,
+            data: crc64_braid_table::<N>(algorithm.width, algorithm.poly, algorithm.refin),
+        }
+    }
+
+    /// Like `new`, but returns an error if `algorithm` is not valid, see [`validate`](crate::validate).
+    pub const fn try_new(algorithm: &'static Algorithm<u64>) -> Result<Self, AlgorithmError> {
+        match validate(algorithm) {
+            Ok(()) => Ok(Self::new(algorithm)),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub const fn table(&self) -> &[[u64; 256]; 9] {
+        &self.data
+    }
+}
+
+impl<const N: usize> private::Kernel<u64> for Braid<N> {
+    fn update(crc: u64, algorithm: &Algorithm<u64>, table: &[[u64; 256]; 9], bytes: &[u8]) -> u64 {
+        update_braids::<N>(crc, algorithm.refin, table, bytes)
+    }
+}
+
+impl Crc<u64, Barrett> {
+    pub const fn new(algorithm: &'static Algorithm<u64>) -> Self {
+        Self {
+            algorithm,
+            data: barrett_constants(algorithm.width, algorithm.poly, algorithm.refin),
+        }
+    }
+
+    /// Like `new`, but returns an error if `algorithm` is not valid, see [`validate`](crate::validate).
+    pub const fn try_new(algorithm: &'static Algorithm<u64>) -> Result<Self, AlgorithmError> {
+        match validate(algorithm) {
+            Ok(()) => Ok(Self::new(algorithm)),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl private::Kernel<u64> for Barrett {
+    fn update(crc: u64, algorithm: &Algorithm<u64>, constants: &[u64; 2], bytes: &[u8]) -> u64 {
+        let width = algorithm.width;
+        if algorithm.refin {
+            update_barrett(crc, width, true, constants, bytes)
+        } else {
+            let crc = crc >> (64u8 - algorithm.width);
+            let crc = update_barrett(crc, width, false, constants, bytes);
+            crc << (64u8 - algorithm.width)
+        }
+    }
+}
+
+impl<const L: usize> private::Build<u64> for Table<L> {
+    fn build(algorithm: &Algorithm<u64>) -> Self::Data<u64> {
+        crc64_table(algorithm.width, algorithm.poly, algorithm.refin)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const L: usize> private::Build<u64> for BoxedTable<L> {
+    fn build(algorithm: &Algorithm<u64>) -> Self::Data<u64> {
+        let mut table = boxed_table();
+        fill_crc64_table(
+            &mut *table,
+            algorithm.width,
+            algorithm.poly,
+            algorithm.refin,
+        );
+        table
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const L: usize> private::Kernel<u64> for BoxedTable<L> {
+    fn update(crc: u64, algorithm: &Algorithm<u64>, table: &Self::Data<u64>, bytes: &[u8]) -> u64 {
+        update_table(crc, algorithm, table, bytes)
+    }
+}
+
+const fn validate(algorithm: &Algorithm<u64>) -> Result<(), AlgorithmError> {
+    let values = [
+        algorithm.poly as u128,
+        algorithm.init as u128,
+        algorithm.xorout as u128,
+        algorithm.check as u128,
+        algorithm.residue as u128,
+    ];
+    validate_fields(u64::BITS, algorithm.width, values)
 }
 
 const fn init(algorithm: &Algorithm<u64>, initial: u64) -> u64 {
@@ -78,6 +560,23 @@ const fn finalize(algorithm: &Algorithm<u64>, mut crc: u64) -> u64 {
     crc ^ algorithm.xorout
 }
 
+/// Advances the register `crc` through `n` zero bytes.
+const fn shift(algorithm: &Algorithm<u64>, crc: u64, n: usize) -> u64 {
+    let poly = algorithm.poly as u128;
+    let value = if algorithm.refin {
+        crc.reverse_bits() >> (64u8 - algorithm.width)
+    } else {
+        crc >> (64u8 - algorithm.width)
+    };
+    let value = mulmod(
+        value as u128,
+        xpow8n(n, poly, algorithm.width),
+        poly,
+        algorithm.width,
+    );
+    init(algorithm, value as u64)
+}
+
 const fn update_table<const L: usize>(
     mut crc: u64,
     algorithm: &Algorithm<u64>,
@@ -88,69 +587,47 @@ const fn update_table<const L: usize>(
     let mut i = 0;
     let reflect = algorithm.refin;
 
-    // Process 16 bytes at a time when L=16
-    if L == 16 {
-        while i + 16 <= len {
-            if reflect {
-                // XOR the first 8 bytes with the current CRC value
-                let current0 = bytes[i] ^ (crc as u8);
-                let current1 = bytes[i + 1] ^ ((crc >> 8) as u8);
-                let current2 = bytes[i + 2] ^ ((crc >> 16) as u8);
-                let current3 = bytes[i + 3] ^ ((crc >> 24) as u8);
-                let current4 = bytes[i + 4] ^ ((crc >> 32) as u8);
-                let current5 = bytes[i + 5] ^ ((crc >> 40) as u8);
-                let current6 = bytes[i + 6] ^ ((crc >> 48) as u8);
-                let current7 = bytes[i + 7] ^ ((crc >> 56) as u8);
-
-                crc = table[0][bytes[i + 15] as usize]
-                    ^ table[1][bytes[i + 14] as usize]
-                    ^ table[2][bytes[i + 13] as usize]
-                    ^ table[3][bytes[i + 12] as usize]
-                    ^ table[4][bytes[i + 11] as usize]
-                    ^ table[5][bytes[i + 10] as usize]
-                    ^ table[6][bytes[i + 9] as usize]
-                    ^ table[7][bytes[i + 8] as usize]
-                    ^ table[8][current7 as usize]
-                    ^ table[9][current6 as usize]
-                    ^ table[10][current5 as usize]
-                    ^ table[11][current4 as usize]
-                    ^ table[12][current3 as usize]
-                    ^ table[13][current2 as usize]
-                    ^ table[14][current1 as usize]
-                    ^ table[15][current0 as usize];
-            } else {
-                // For non-reflected CRC64
-                let current0 = bytes[i] ^ ((crc >> 56) as u8);
-                let current1 = bytes[i + 1] ^ ((crc >> 48) as u8);
-                let current2 = bytes[i + 2] ^ ((crc >> 40) as u8);
-                let current3 = bytes[i + 3] ^ ((crc >> 32) as u8);
-                let current4 = bytes[i + 4] ^ ((crc >> 24) as u8);
-                let current5 = bytes[i + 5] ^ ((crc >> 16) as u8);
-                let current6 = bytes[i + 6] ^ ((crc >> 8) as u8);
-                let current7 = bytes[i + 7] ^ (crc as u8);
-
-                crc = table[0][bytes[i + 15] as usize]
-                    ^ table[1][bytes[i + 14] as usize]
-                    ^ table[2][bytes[i + 13] as usize]
-                    ^ table[3][bytes[i + 12] as usize]
-                    ^ table[4][bytes[i + 11] as usize]
-                    ^ table[5][bytes[i + 10] as usize]
-                    ^ table[6][bytes[i + 9] as usize]
-                    ^ table[7][bytes[i + 8] as usize]
-                    ^ table[8][current7 as usize]
-                    ^ table[9][current6 as usize]
-                    ^ table[10][current5 as usize]
-                    ^ table[11][current4 as usize]
-                    ^ table[12][current3 as usize]
-                    ^ table[13][current2 as usize]
-                    ^ table[14][current1 as usize]
-                    ^ table[15][current0 as usize];
+    // Process L bytes at a time using the slicing tables. The first (up to) 8 bytes
+    // are combined with the current CRC value, whose remaining bytes are carried over.
+    // The lookups that don't depend on the CRC value come first to shorten the
+    // dependency chain between iterations.
+    if L > 1 {
+        if reflect {
+            while i + L <= len {
+                let chunk = bytes.split_at(i).1.split_at(L).0;
+                let mut next = 0;
+                let mut j = L;
+                while j > 0 {
+                    j -= 1;
+                    let mut byte = chunk[j];
+                    if j < 8 {
+                        byte ^= (crc >> (8 * j)) as u8;
+                    }
+                    next ^= table[L - 1 - j][byte as usize];
+                }
+                crc = if L < 8 { next ^ (crc >> (8 * L)) } else { next };
+                i += L;
+            }
+        } else {
+            while i + L <= len {
+                let chunk = bytes.split_at(i).1.split_at(L).0;
+                let mut next = 0;
+                let mut j = L;
+                while j > 0 {
+                    j -= 1;
+                    let mut byte = chunk[j];
+                    if j < 8 {
+                        byte ^= (crc >> (56 - 8 * j)) as u8;
+                    }
+                    next ^= table[L - 1 - j][byte as usize];
+                }
+                crc = if L < 8 { next ^ (crc << (8 * L)) } else { next };
+                i += L;
             }
-            i += 16;
         }
     }
 
-    // Process remaining bytes one at a time using the table (for L=1 and L=16)
+    // Process remaining bytes one at a time using the first table
     if L > 0 {
         if reflect {
             while i < len {
@@ -192,6 +669,150 @@ const fn update_table<const L: usize>(
     crc
 }
 
+/// Updates the first `LANES` messages of `inputs` in lockstep, returning the
+/// unfinalized registers.
+const fn update_lanes<const L: usize>(
+    algorithm: &Algorithm<u64>,
+    table: &[[u64; 256]; L],
+    inputs: &[&[u8]],
+) -> [u64; LANES] {
+    let mut crcs = [init(algorithm, algorithm.init); LANES];
+    let reflect = algorithm.refin;
+
+    let mut len = inputs[0].len();
+    let mut lane = 1;
+    while lane < LANES {
+        if inputs[lane].len() < len {
+            len = inputs[lane].len();
+        }
+        lane += 1;
+    }
+
+    let mut k = 0;
+    while k < len {
+        let mut lane = 0;
+        if reflect {
+            while lane < LANES {
+                let index = ((crcs[lane] ^ inputs[lane][k] as u64) & 0xFF) as usize;
+                crcs[lane] = table[0][index] ^ (crcs[lane] >> 8);
+                lane += 1;
+            }
+        } else {
+            while lane < LANES {
+                let index = (((crcs[lane] >> 56) ^ inputs[lane][k] as u64) & 0xFF) as usize;
+                crcs[lane] = table[0][index] ^ (crcs[lane] << 8);
+                lane += 1;
+            }
+        }
+        k += 1;
+    }
+
+    let mut lane = 0;
+    while lane < LANES {
+        crcs[lane] = update_table(crcs[lane], algorithm, table, inputs[lane].split_at(len).1);
+        lane += 1;
+    }
+    crcs
+}
+
+const fn update_nibbles(mut crc: u64, reflect: bool, table: &[u64; 16], bytes: &[u8]) -> u64 {
+    let mut i = 0;
+    if reflect {
+        while i < bytes.len() {
+            crc ^= bytes[i] as u64;
+            crc = (crc >> 4) ^ table[(crc & 0xF) as usize];
+            crc = (crc >> 4) ^ table[(crc & 0xF) as usize];
+            i += 1;
+        }
+    } else {
+        while i < bytes.len() {
+            crc ^= (bytes[i] as u64) << 56;
+            crc = (crc << 4) ^ table[(crc >> 60) as usize];
+            crc = (crc << 4) ^ table[(crc >> 60) as usize];
+            i += 1;
+        }
+    }
+    crc
+}
+
+/// Processes the input as `N` braids of 64-bit words, where braid `k` holds the words
+/// `k`, `k + N`, `k + 2 * N`, etc. Each braid has its own register, and the registers are
+/// combined by feeding them through the last block word by word.
+const fn update_braids<const N: usize>(
+    mut crc: u64,
+    reflect: bool,
+    table: &[[u64; 256]; 9],
+    bytes: &[u8],
+) -> u64 {
+    let len = bytes.len();
+    let mut i = 0;
+
+    let blocks = len / (8 * N);
+    if blocks > 1 {
+        let mut crcs = [0; N];
+        crcs[0] = crc;
+        while i < (blocks - 1) * 8 * N {
+            let mut k = 0;
+            while k < N {
+                let word = if reflect {
+                    u64::from_le_bytes(*bytes.split_at(i + 8 * k).1.first_chunk().unwrap())
+                        ^ crcs[k]
+                } else {
+                    u64::from_be_bytes(*bytes.split_at(i + 8 * k).1.first_chunk().unwrap())
+                        ^ crcs[k]
+                };
+                let mut next = 0;
+                let mut j = 0;
+                while j < 8 {
+                    let byte = if reflect {
+                        word >> (8 * j)
+                    } else {
+                        word >> (56 - 8 * j)
+                    };
+                    next ^= table[1 + j][(byte & 0xFF) as usize];
+                    j += 1;
+                }
+                crcs[k] = next;
+                k += 1;
+            }
+            i += 8 * N;
+        }
+
+        crc = 0;
+        let mut k = 0;
+        while k < N {
+            let word = if reflect {
+                (u64::from_le_bytes(*bytes.split_at(i + 8 * k).1.first_chunk().unwrap()) ^ crcs[k])
+                    .to_le_bytes()
+            } else {
+                (u64::from_be_bytes(*bytes.split_at(i + 8 * k).1.first_chunk().unwrap()) ^ crcs[k])
+                    .to_be_bytes()
+            };
+            crc = update_bytewise(crc, reflect, &table[0], &word);
+            k += 1;
+        }
+        i += 8 * N;
+    }
+
+    update_bytewise(crc, reflect, &table[0], bytes.split_at(i).1)
+}
+
+const fn update_bytewise(mut crc: u64, reflect: bool, table: &[u64; 256], bytes: &[u8]) -> u64 {
+    let mut i = 0;
+    if reflect {
+        while i < bytes.len() {
+            crc = table[((crc ^ bytes[i] as u64) & 0xFF) as usize] ^ (crc >> 8);
+            i += 1;
+        }
+    } else {
+        while i < bytes.len() {
+            crc = table[(((crc >> 56) ^ bytes[i] as u64) & 0xFF) as usize] ^ (crc << 8);
+            i += 1;
+        }
+    }
+    crc
+}
+
 #[cfg(test)]
 mod test {
     use crate::*;
@@ -229,6 +850,23 @@ mod test {
                 let crc_nolookup = Crc::<u64, NoTable>::new(alg);
                 let expected = Crc::<u64, Table<1>>::new(alg).checksum(data.as_bytes());
 
+                // Check the other lane counts, including ones wider than the CRC
+                let bytes = data.as_bytes();
+                assert_eq!(Crc::<u64, Table<2>>::new(alg).checksum(bytes), expected);
+                assert_eq!(Crc::<u64, Table<3>>::new(alg).checksum(bytes), expected);
+                assert_eq!(Crc::<u64, Table<4>>::new(alg).checksum(bytes), expected);
+                assert_eq!(Crc::<u64, Table<8>>::new(alg).checksum(bytes), expected);
+                assert_eq!(Crc::<u64, Table<32>>::new(alg).checksum(bytes), expected);
+                assert_eq!(Crc::<u64, NibbleTable>::new(alg).checksum(bytes), expected);
+                assert_eq!(Crc::<u64, Barrett>::new(alg).checksum(bytes), expected);
+                assert_eq!(Crc::<u64, Braid<1>>::new(alg).checksum(bytes), expected);
+                assert_eq!(Crc::<u64, Braid<5>>::new(alg).checksum(bytes), expected);
+                #[cfg(feature = "alloc")]
+                assert_eq!(
+                    Crc::<u64, BoxedTable<16>>::new(alg).checksum(bytes),
+                    expected
+                );
+
                 // Check that doing all at once works as expected
                 assert_eq!(crc_slice16.checksum(data.as_bytes()), expected);
                 assert_eq!(crc_nolookup.checksum(data.as_bytes()), expected);
@@ -1,5 +1,9 @@
-use crate::table::crc8_table;
-use crate::util::crc8;
+#[cfg(feature = "alloc")]
+use crate::table::{boxed_table, fill_crc8_table};
+use crate::table::{crc8_nibble_table, crc8_table};
+use crate::util::{
+    barrett_constants, crc8, mulmod, residue, update_barrett, validate as validate_fields, xpow8n,
+};
 use crate::*;
 use crc_catalog::Algorithm;
 
@@ -14,16 +18,102 @@ where
         }
     }
 
+    /// Like `new`, but returns an error if `algorithm` is not valid, see [`validate`](crate::validate).
+    pub const fn try_new(algorithm: &'static Algorithm<u8>) -> Result<Self, AlgorithmError> {
+        match validate(algorithm) {
+            Ok(()) => Ok(Self::new(algorithm)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Checks that the checksum of `"123456789"` matches the `check` of the algorithm.
+    pub const fn verify_check(&self) -> Result<(), AlgorithmError> {
+        if self.checksum(b"123456789") == self.algorithm.check {
+            Ok(())
+        } else {
+            Err(AlgorithmError::CheckMismatch)
+        }
+    }
+
     pub const fn checksum(&self, bytes: &[u8]) -> u8 {
         let mut crc = init(self.algorithm, self.algorithm.init);
         crc = self.update(crc, bytes);
         finalize(self.algorithm, crc)
     }
 
+    /// Like `checksum`, but returns a [`Checksum`] that knows the width of the algorithm.
+    pub const fn typed_checksum(&self, bytes: &[u8]) -> Checksum<u8> {
+        Checksum {
+            value: self.checksum(bytes),
+            width: self.algorithm.width,
+        }
+    }
+
+    /// Computes the checksums of several independent messages into `out`.
+    ///
+    /// With a bytewise table, messages are processed in interleaved lanes so that
+    /// the table lookups of different messages can overlap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `inputs` and `out` differ in length.
+    pub const fn checksum_many(&self, inputs: &[&[u8]], out: &mut [u8]) {
+        assert!(inputs.len() == out.len(), "inputs and out differ in length");
+        let mut i = 0;
+        if L == 1 {
+            while i + LANES <= inputs.len() {
+                let crcs = update_lanes(self.algorithm, &self.data, inputs.split_at(i).1);
+                let mut lane = 0;
+                while lane < LANES {
+                    out[i + lane] = finalize(self.algorithm, crcs[lane]);
+                    lane += 1;
+                }
+                i += LANES;
+            }
+        }
+        while i < inputs.len() {
+            out[i] = self.checksum(inputs[i]);
+            i += 1;
+        }
+    }
+
     const fn update(&self, crc: u8, bytes: &[u8]) -> u8 {
         update_table(crc, self.algorithm, &self.data, bytes)
     }
 
+    /// Updates the checksum `crc` of a `len`-byte message after the bytes at `offset` have
+    /// been changed from `old` to `new`, without reprocessing the rest of the message.
+    ///
+    /// The cost is proportional to the length of the edit and the logarithm of `len`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `old` and `new` differ in length or the edit extends past `len`.
+    pub const fn patch(&self, crc: u8, len: usize, offset: usize, old: &[u8], new: &[u8]) -> u8 {
+        assert!(old.len() == new.len(), "old and new bytes differ in length");
+        assert!(
+            offset <= len && old.len() <= len - offset,
+            "edit extends past message"
+        );
+
+        // CRCs are affine, so the checksum changes by the CRC of the XOR difference
+        // computed with zero init and xorout, followed by the unchanged trailing bytes.
+        let mut diff = 0;
+        let mut buf = [0u8; 64];
+        let mut i = 0;
+        while i < old.len() {
+            let mut n = 0;
+            while n < buf.len() && i < old.len() {
+                buf[n] = old[i] ^ new[i];
+                n += 1;
+                i += 1;
+            }
+            diff = self.update(diff, buf.split_at(n).0);
+        }
+        diff = shift(self.algorithm, diff, len - offset - old.len());
+        crc ^ finalize(self.algorithm, diff) ^ self.algorithm.xorout
+    }
+
     pub const fn digest(&self) -> Digest<'_, u8, Table<L>> {
         self.digest_with_initial(self.algorithm.init)
     }
@@ -33,6 +123,10 @@ where
     /// This overrides the initial value specified by the algorithm.
     /// The effects of the algorithm's properties `refin` and `width`
     /// are applied to the custom initial value.
+    ///
+    /// The initial value is in the direct form of `Algorithm::init`, see
+    /// [`Poly::direct_init`](crate::poly::Poly::direct_init) to convert the initial
+    /// register of an augmented algorithm.
     pub const fn digest_with_initial(&self, initial: u8) -> Digest<'_, u8, Table<L>> {
         let value = init(self.algorithm, initial);
         Digest::new(self, value)
@@ -55,9 +149,371 @@ where
         self.value = self.crc.update(self.value, bytes);
     }
 
+    /// Updates the digest with each of `slices` in order, as if they were contiguous.
+    pub const fn update_slices(&mut self, slices: &[&[u8]]) {
+        let mut i = 0;
+        while i < slices.len() {
+            self.update(slices[i]);
+            i += 1;
+        }
+    }
+
+    /// Updates the digest with each of `slices` in order, as if they were contiguous.
+    #[cfg(feature = "std")]
+    pub fn update_vectored(&mut self, slices: &[std::io::IoSlice<'_>]) {
+        for slice in slices {
+            self.update(slice);
+        }
+    }
+
+    /// Updates the digest with the bytes of `value` in the given byte order.
+    pub const fn update_u16(&mut self, value: u16, endian: Endian) {
+        match endian {
+            Endian::Big => self.update(&value.to_be_bytes()),
+            Endian::Little => self.update(&value.to_le_bytes()),
+        }
+    }
+
+    /// Updates the digest with the bytes of `value` in the given byte order.
+    pub const fn update_u32(&mut self, value: u32, endian: Endian) {
+        match endian {
+            Endian::Big => self.update(&value.to_be_bytes()),
+            Endian::Little => self.update(&value.to_le_bytes()),
+        }
+    }
+
+    /// Updates the digest with the bytes of `value` in the given byte order.
+    pub const fn update_u64(&mut self, value: u64, endian: Endian) {
+        match endian {
+            Endian::Big => self.update(&value.to_be_bytes()),
+            Endian::Little => self.update(&value.to_le_bytes()),
+        }
+    }
+
+    /// Updates the digest with `words`, each in little-endian byte order.
+    pub const fn update_words_le(&mut self, words: &[u32]) {
+        self.update_words(words, Endian::Little);
+    }
+
+    /// Updates the digest with `words`, each in big-endian byte order.
+    pub const fn update_words_be(&mut self, words: &[u32]) {
+        self.update_words(words, Endian::Big);
+    }
+
+    const fn update_words(&mut self, words: &[u32], endian: Endian) {
+        let mut buf = [0u8; 64];
+        let mut i = 0;
+        while i < words.len() {
+            let mut n = 0;
+            while n < buf.len() && i < words.len() {
+                let bytes = match endian {
+                    Endian::Big => words[i].to_be_bytes(),
+                    Endian::Little => words[i].to_le_bytes(),
+                };
+                buf[n] = bytes[0];
+                buf[n + 1] = bytes[1];
+                buf[n + 2] = bytes[2];
+                buf[n + 3] = bytes[3];
+                n += 4;
+                i += 1;
+            }
+            self.update(buf.split_at(n).0);
+        }
+    }
+
+    /// Updates the digest with the bytes yielded by `bytes`.
+    pub fn update_iter(&mut self, bytes: impl IntoIterator<Item = u8>) {
+        let mut buf = [0u8; 64];
+        let mut n = 0;
+        for byte in bytes {
+            buf[n] = byte;
+            n += 1;
+            if n == buf.len() {
+                self.update(&buf);
+                n = 0;
+            }
+        }
+        self.update(&buf[..n]);
+    }
+
     pub const fn finalize(self) -> u8 {
         finalize(self.crc.algorithm, self.value)
     }
+
+    /// Like `finalize`, but returns a [`Checksum`] that knows the width of the algorithm.
+    pub const fn finalize_typed(self) -> Checksum<u8> {
+        Checksum {
+            value: finalize(self.crc.algorithm, self.value),
+            width: self.crc.algorithm.width,
+        }
+    }
+}
+
+impl<const L: usize> Extend<u8> for Digest<'_, u8, Table<L>>
+where
+    Table<L>: private::Sealed,
+{
+    fn extend<T: IntoIterator<Item = u8>>(&mut self, bytes: T) {
+        self.update_iter(bytes);
+    }
+}
+
+impl<'b, const L: usize> Extend<&'b u8> for Digest<'_, u8, Table<L>>
+where
+    Table<L>: private::Sealed,
+{
+    fn extend<T: IntoIterator<Item = &'b u8>>(&mut self, bytes: T) {
+        self.update_iter(bytes.into_iter().copied());
+    }
+}
+
+impl AlgorithmBuilder<u8> {
+    /// Starts an algorithm with the given `width` and `poly`, a zero `init` and `xorout`,
+    /// and no reflection.
+    pub const fn new(width: u8, poly: u8) -> Self {
+        Self {
+            algorithm: Algorithm {
+                width,
+                poly,
+                init: 0,
+                refin: false,
+                refout: false,
+                xorout: 0,
+                check: 0,
+                residue: 0,
+            },
+        }
+    }
+
+    pub const fn init(mut self, init: u8) -> Self {
+        self.algorithm.init = init;
+        self
+    }
+
+    pub const fn refin(mut self, refin: bool) -> Self {
+        self.algorithm.refin = refin;
+        self
+    }
+
+    pub const fn refout(mut self, refout: bool) -> Self {
+        self.algorithm.refout = refout;
+        self
+    }
+
+    pub const fn xorout(mut self, xorout: u8) -> Self {
+        self.algorithm.xorout = xorout;
+        self
+    }
+
+    /// Computes `check` and `residue`, and returns the algorithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the algorithm is not valid, see [`validate`](crate::validate).
+    pub const fn build(self) -> Algorithm<u8> {
+        let mut algorithm = self.algorithm;
+        assert!(validate(&algorithm).is_ok(), "invalid algorithm");
+        let crc = init(&algorithm, algorithm.init);
+        let crc = update_table::<0>(crc, &algorithm, &[], b"123456789");
+        algorithm.check = finalize(&algorithm, crc);
+        algorithm.residue = residue(
+            algorithm.width,
+            algorithm.poly as u128,
+            algorithm.xorout as u128,
+            algorithm.refin,
+            algorithm.refout,
+        ) as u8;
+        algorithm
+    }
+}
+
+impl private::Register for u8 {
+    fn init(algorithm: &Algorithm<u8>, initial: u8) -> u8 {
+        init(algorithm, initial)
+    }
+
+    fn finalize(algorithm: &Algorithm<u8>, crc: u8) -> u8 {
+        finalize(algorithm, crc)
+    }
+
+    fn shift(algorithm: &Algorithm<u8>, crc: u8, n: usize) -> u8 {
+        shift(algorithm, crc, n)
+    }
+
+    fn validate(algorithm: &Algorithm<u8>) -> Result<(), AlgorithmError> {
+        validate(algorithm)
+    }
+
+    fn checksum(algorithm: &Algorithm<u8>, bytes: &[u8]) -> u8 {
+        let crc = init(algorithm, algorithm.init);
+        finalize(algorithm, update_table::<0>(crc, algorithm, &[], bytes))
+    }
+}
+
+impl<const L: usize> CrcTable<u8, L> {
+    pub const fn new(algorithm: &Algorithm<u8>) -> Self {
+        Self {
+            width: algorithm.width,
+            poly: algorithm.poly,
+            refin: algorithm.refin,
+            table: crc8_table(algorithm.width, algorithm.poly, algorithm.refin),
+        }
+    }
+
+    /// Like `new`, but returns an error if `algorithm` is not valid, see [`validate`](crate::validate).
+    pub const fn try_new(algorithm: &Algorithm<u8>) -> Result<Self, AlgorithmError> {
+        match validate(algorithm) {
+            Ok(()) => Ok(Self::new(algorithm)),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub const fn table(&self) -> &[[u8; 256]; L] {
+        &self.table
+    }
+}
+
+impl<const L: usize> Crc<u8, SharedTable<L>> {
+    /// Construct a `Crc` that uses the shared lookup `table`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `table` was built for a different `width`, `poly` or `refin`.
+    pub const fn with_table(
+        algorithm: &'static Algorithm<u8>,
+        table: &'static CrcTable<u8, L>,
+    ) -> Self {
+        assert!(
+            table.width == algorithm.width
+                && table.poly == algorithm.poly
+                && table.refin == algorithm.refin,
+            "table does not match algorithm"
+        );
+        Self {
+            algorithm,
+            data: table,
+        }
+    }
+
+    /// Like `with_table`, but returns an error if `algorithm` is not valid, see
+    /// [`validate`](crate::validate), or if `table` does not match it.
+    pub const fn try_with_table(
+        algorithm: &'static Algorithm<u8>,
+        table: &'static CrcTable<u8, L>,
+    ) -> Result<Self, AlgorithmError> {
+        if let Err(err) = validate(algorithm) {
+            return Err(err);
+        }
+        if table.width != algorithm.width
+            || table.poly != algorithm.poly
+            || table.refin != algorithm.refin
+        {
+            return Err(AlgorithmError::TableMismatch);
+        }
+        Ok(Self::with_table(algorithm, table))
+    }
+}
+
+impl<const L: usize> private::Kernel<u8> for SharedTable<L> {
+    fn update(crc: u8, algorithm: &Algorithm<u8>, table: &Self::Data<u8>, bytes: &[u8]) -> u8 {
+        update_table(crc, algorithm, &table.table, bytes)
+    }
+}
+
+impl Crc<u8, NibbleTable> {
+    pub const fn new(algorithm: &'static Algorithm<u8>) -> Self {
+        Self {
+            algorithm,
+            data: crc8_nibble_table(algorithm.width, algorithm.poly, algorithm.refin),
+        }
+    }
+
+    /// Like `new`, but returns an error if `algorithm` is not valid, see [`validate`](crate::validate).
+    pub const fn try_new(algorithm: &'static Algorithm<u8>) -> Result<Self, AlgorithmError> {
+        match validate(algorithm) {
+            Ok(()) => Ok(Self::new(algorithm)),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub const fn table(&self) -> &[u8; 16] {
+        &self.data
+    }
+}
+
+impl private::Kernel<u8> for NibbleTable {
+    fn update(crc: u8, algorithm: &Algorithm<u8>, table: &[u8; 16], bytes: &[u8]) -> u8 {
+        update_nibbles(crc, algorithm.refin, table, bytes)
+    }
+}
+
+impl Crc<u8, Barrett> {
+    pub const fn new(algorithm: &'static Algorithm<u8>) -> Self {
+        Self {
+            algorithm,
+            data: barrett_constants(algorithm.width, algorithm.poly as u64, algorithm.refin),
+        }
+    }
+
+    /// Like `new`, but returns an error if `algorithm` is not valid, see [`validate`](crate::validate).
+    pub const fn try_new(algorithm: &'static Algorithm<u8>) -> Result<Self, AlgorithmError> {
+        match validate(algorithm) {
+            Ok(()) => Ok(Self::new(algorithm)),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl private::Kernel<u8> for Barrett {
+    fn update(crc: u8, algorithm: &Algorithm<u8>, constants: &[u64; 2], bytes: &[u8]) -> u8 {
+        let width = algorithm.width;
+        if algorithm.refin {
+            let crc = update_barrett(crc as u64, width, true, constants, bytes);
+            crc as u8
+        } else {
+            let crc = (crc >> (8u8 - algorithm.width)) as u64;
+            let crc = update_barrett(crc, width, false, constants, bytes);
+            (crc as u8) << (8u8 - algorithm.width)
+        }
+    }
+}
+
+impl<const L: usize> private::Build<u8> for Table<L> {
+    fn build(algorithm: &Algorithm<u8>) -> Self::Data<u8> {
+        crc8_table(algorithm.width, algorithm.poly, algorithm.refin)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const L: usize> private::Build<u8> for BoxedTable<L> {
+    fn build(algorithm: &Algorithm<u8>) -> Self::Data<u8> {
+        let mut table = boxed_table();
+        fill_crc8_table(
+            &mut *table,
+            algorithm.width,
+            algorithm.poly,
+            algorithm.refin,
+        );
+        table
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const L: usize> private::Kernel<u8> for BoxedTable<L> {
+    fn update(crc: u8, algorithm: &Algorithm<u8>, table: &Self::Data<u8>, bytes: &[u8]) -> u8 {
+        update_table(crc, algorithm, table, bytes)
+    }
+}
+
+const fn validate(algorithm: &Algorithm<u8>) -> Result<(), AlgorithmError> {
+    let values = [
+        algorithm.poly as u128,
+        algorithm.init as u128,
+        algorithm.xorout as u128,
+        algorithm.check as u128,
+        algorithm.residue as u128,
+    ];
+    validate_fields(u8::BITS, algorithm.width, values)
 }
 
 const fn init(algorithm: &Algorithm<u8>, initial: u8) -> u8 {
@@ -78,6 +534,23 @@ const fn finalize(algorithm: &Algorithm<u8>, mut crc: u8) -> u8 {
     crc ^ algorithm.xorout
 }
 
+/// Advances the register `crc` through `n` zero bytes.
+const fn shift(algorithm: &Algorithm<u8>, crc: u8, n: usize) -> u8 {
+    let poly = algorithm.poly as u128;
+    let value = if algorithm.refin {
+        crc.reverse_bits() >> (8u8 - algorithm.width)
+    } else {
+        crc >> (8u8 - algorithm.width)
+    };
+    let value = mulmod(
+        value as u128,
+        xpow8n(n, poly, algorithm.width),
+        poly,
+        algorithm.width,
+    );
+    init(algorithm, value as u8)
+}
+
 const fn update_table<const L: usize>(
     mut crc: u8,
     algorithm: &Algorithm<u8>,
@@ -87,30 +560,23 @@ const fn update_table<const L: usize>(
     let len = bytes.len();
     let mut i = 0;
 
-    // Process 16 bytes at a time when L=16
-    if L == 16 {
-        while i + 16 <= len {
-            crc = table[0][bytes[i + 15] as usize]
-                ^ table[1][bytes[i + 14] as usize]
-                ^ table[2][bytes[i + 13] as usize]
-                ^ table[3][bytes[i + 12] as usize]
-                ^ table[4][bytes[i + 11] as usize]
-                ^ table[5][bytes[i + 10] as usize]
-                ^ table[6][bytes[i + 9] as usize]
-                ^ table[7][bytes[i + 8] as usize]
-                ^ table[8][bytes[i + 7] as usize]
-                ^ table[9][bytes[i + 6] as usize]
-                ^ table[10][bytes[i + 5] as usize]
-                ^ table[11][bytes[i + 4] as usize]
-                ^ table[12][bytes[i + 3] as usize]
-                ^ table[13][bytes[i + 2] as usize]
-                ^ table[14][bytes[i + 1] as usize]
-                ^ table[15][(bytes[i] ^ crc) as usize];
-            i += 16;
-        }
-    }
-
-    // Process remaining bytes one at a time using the table (for L=1 and L=16)
+    // Process L bytes at a time using the slicing tables. The lookups that don't
+    // depend on the current CRC value come first to shorten the dependency chain.
+    if L > 1 {
+        while i + L <= len {
+            let chunk = bytes.split_at(i).1.split_at(L).0;
+            let mut next = 0;
+            let mut j = L - 1;
+            while j > 0 {
+                next ^= table[L - 1 - j][chunk[j] as usize];
+                j -= 1;
+            }
+            crc = next ^ table[L - 1][(chunk[0] ^ crc) as usize];
+            i += L;
+        }
+    }
+
+    // Process remaining bytes one at a time using the first table
     if L > 0 {
         while i < len {
             crc = table[0][(crc ^ bytes[i]) as usize];
@@ -134,6 +600,58 @@ const fn update_table<const L: usize>(
     crc
 }
 
+/// Updates the first `LANES` messages of `inputs` in lockstep, returning the
+/// unfinalized registers.
+const fn update_lanes<const L: usize>(
+    algorithm: &Algorithm<u8>,
+    table: &[[u8; 256]; L],
+    inputs: &[&[u8]],
+) -> [u8; LANES] {
+    let mut crcs = [init(algorithm, algorithm.init); LANES];
+
+    let mut len = inputs[0].len();
+    let mut lane = 1;
+    while lane < LANES {
+        if inputs[lane].len() < len {
+            len = inputs[lane].len();
+        }
+        lane += 1;
+    }
+
+    let mut k = 0;
+    while k < len {
+        let mut lane = 0;
+        while lane < LANES {
+            crcs[lane] = table[0][(crcs[lane] ^ inputs[lane][k]) as usize];
+            lane += 1;
+        }
+        k += 1;
+    }
+
+    let mut lane = 0;
+    while lane < LANES {
+        crcs[lane] = update_table(crcs[lane], algorithm, table, inputs[lane].split_at(len).1);
+        lane += 1;
+    }
+    crcs
+}
+
+const fn update_nibbles(mut crc: u8, reflect: bool, table: &[u8; 16], bytes: &[u8]) -> u8 {
+    let mut i = 0;
+    while i < bytes.len() {
+        crc ^= bytes[i];
+        if reflect {
+            crc = (crc >> 4) ^ table[(crc & 0xF) as usize];
+            crc = (crc >> 4) ^ table[(crc & 0xF) as usize];
+        } else {
+            crc = (crc << 4) ^ table[(crc >> 4) as usize];
+            crc = (crc << 4) ^ table[(crc >> 4) as usize];
+        }
+        i += 1;
+    }
+    crc
+}
+
 #[cfg(test)]
 mod test {
     use crate::*;
@@ -168,10 +686,24 @@ mod test {
         for alg in algs_to_test {
             for data in data {
                 let crc_slice16 = Crc::<u8, Table<16>>::new(alg);
-                // let crc_slice8 = Crc::<u8, Table<8>>::new(alg);
                 let crc_nolookup = Crc::<u8, NoTable>::new(alg);
                 let expected = Crc::<u8, Table<1>>::new(alg).checksum(data.as_bytes());
 
+                // Check the other lane counts, including ones wider than the CRC
+                let bytes = data.as_bytes();
+                assert_eq!(Crc::<u8, Table<2>>::new(alg).checksum(bytes), expected);
+                assert_eq!(Crc::<u8, Table<3>>::new(alg).checksum(bytes), expected);
+                assert_eq!(Crc::<u8, Table<4>>::new(alg).checksum(bytes), expected);
+                assert_eq!(Crc::<u8, Table<8>>::new(alg).checksum(bytes), expected);
+                assert_eq!(Crc::<u8, Table<32>>::new(alg).checksum(bytes), expected);
+                assert_eq!(Crc::<u8, NibbleTable>::new(alg).checksum(bytes), expected);
+                assert_eq!(Crc::<u8, Barrett>::new(alg).checksum(bytes), expected);
+                #[cfg(feature = "alloc")]
+                assert_eq!(
+                    Crc::<u8, BoxedTable<16>>::new(alg).checksum(bytes),
+                    expected
+                );
+
                 // Check that doing all at once works as expected
                 assert_eq!(crc_slice16.checksum(data.as_bytes()), expected);
                 assert_eq!(crc_nolookup.checksum(data.as_bytes()), expected);
@@ -0,0 +1,229 @@
+use crate::private::{Build, Kernel, Register};
+use crate::*;
+use crc_catalog::Algorithm;
+
+impl<W: Register, I: Kernel<W> + Build<W>> Crc<W, I> {
+    pub fn new(algorithm: &'static Algorithm<W>) -> Self {
+        Self {
+            algorithm,
+            data: I::build(algorithm),
+        }
+    }
+
+    /// Like `new`, but returns an error if `algorithm` is not valid, see [`validate`].
+    pub fn try_new(algorithm: &'static Algorithm<W>) -> Result<Self, AlgorithmError> {
+        W::validate(algorithm)?;
+        Ok(Self::new(algorithm))
+    }
+}
+
+impl<W: Register, I: Kernel<W>> Crc<W, I> {
+    pub fn checksum(&self, bytes: &[u8]) -> W {
+        let mut crc = W::init(self.algorithm, self.algorithm.init);
+        crc = self.update(crc, bytes);
+        W::finalize(self.algorithm, crc)
+    }
+
+    /// Like `checksum`, but returns a [`Checksum`] that knows the width of the algorithm.
+    pub fn typed_checksum(&self, bytes: &[u8]) -> Checksum<W> {
+        Checksum {
+            value: self.checksum(bytes),
+            width: self.algorithm.width,
+        }
+    }
+
+    /// Checks that the checksum of `"123456789"` matches the `check` of the algorithm.
+    pub fn verify_check(&self) -> Result<(), AlgorithmError> {
+        if self.checksum(b"123456789") == self.algorithm.check {
+            Ok(())
+        } else {
+            Err(AlgorithmError::CheckMismatch)
+        }
+    }
+
+    /// Computes the checksums of several independent messages into `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `inputs` and `out` differ in length.
+    pub fn checksum_many(&self, inputs: &[&[u8]], out: &mut [W]) {
+        assert!(inputs.len() == out.len(), "inputs and out differ in length");
+        for (bytes, out) in inputs.iter().zip(out) {
+            *out = self.checksum(bytes);
+        }
+    }
+
+    fn update(&self, crc: W, bytes: &[u8]) -> W {
+        I::update(crc, self.algorithm, &self.data, bytes)
+    }
+
+    /// Updates the checksum `crc` of a `len`-byte message after the bytes at `offset` have
+    /// been changed from `old` to `new`, without reprocessing the rest of the message.
+    ///
+    /// The cost is proportional to the length of the edit and the logarithm of `len`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `old` and `new` differ in length or the edit extends past `len`.
+    pub fn patch(&self, crc: W, len: usize, offset: usize, old: &[u8], new: &[u8]) -> W {
+        assert!(old.len() == new.len(), "old and new bytes differ in length");
+        assert!(
+            offset <= len && old.len() <= len - offset,
+            "edit extends past message"
+        );
+
+        // See the `Table<L>` implementation.
+        let mut diff = W::default();
+        let mut buf = [0u8; 64];
+        for (old, new) in old.chunks(buf.len()).zip(new.chunks(buf.len())) {
+            for (i, (old, new)) in old.iter().zip(new).enumerate() {
+                buf[i] = old ^ new;
+            }
+            diff = self.update(diff, &buf[..old.len()]);
+        }
+        diff = W::shift(self.algorithm, diff, len - offset - old.len());
+        crc ^ W::finalize(self.algorithm, diff) ^ self.algorithm.xorout
+    }
+
+    pub fn digest(&self) -> Digest<'_, W, I> {
+        self.digest_with_initial(self.algorithm.init)
+    }
+
+    /// Construct a `Digest` with a given initial value.
+    ///
+    /// This overrides the initial value specified by the algorithm.
+    /// The effects of the algorithm's properties `refin` and `width`
+    /// are applied to the custom initial value.
+    ///
+    /// The initial value is in the direct form of `Algorithm::init`, see
+    /// [`Poly::direct_init`](crate::poly::Poly::direct_init) to convert the initial
+    /// register of an augmented algorithm.
+    pub fn digest_with_initial(&self, initial: W) -> Digest<'_, W, I> {
+        let value = W::init(self.algorithm, initial);
+        Digest { crc: self, value }
+    }
+}
+
+/// Checks that `algorithm` is valid for the register type `W`, i.e. that `width` is
+/// between 1 and the number of bits of `W`, and that `poly`, `init`, `xorout`, `check`
+/// and `residue` fit into `width` bits.
+///
+/// This does not verify `check` itself, see `Crc::verify_check`.
+///
+/// ```rust
+/// use crc::{validate, Algorithm, AlgorithmError, CRC_16_IBM_SDLC};
+///
+/// assert_eq!(validate(&CRC_16_IBM_SDLC), Ok(()));
+/// let truncated = Algorithm { width: 12, ..CRC_16_IBM_SDLC };
+/// assert_eq!(validate(&truncated), Err(AlgorithmError::Poly));
+/// ```
+pub fn validate<W: Register>(algorithm: &Algorithm<W>) -> Result<(), AlgorithmError> {
+    W::validate(algorithm)
+}
+
+#[cfg(feature = "alloc")]
+impl<W: Register, const L: usize> Crc<W, BoxedTable<L>> {
+    pub fn table(&self) -> &[[W; 256]; L] {
+        &self.data
+    }
+}
+
+impl<W: Register, const L: usize> Crc<W, SharedTable<L>> {
+    pub fn table(&self) -> &'static CrcTable<W, L> {
+        self.data
+    }
+}
+
+impl<W: Register, I: Kernel<W>> Digest<'_, W, I> {
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.value = self.crc.update(self.value, bytes);
+    }
+
+    /// Updates the digest with each of `slices` in order, as if they were contiguous.
+    pub fn update_slices(&mut self, slices: &[&[u8]]) {
+        for slice in slices {
+            self.update(slice);
+        }
+    }
+
+    /// Updates the digest with each of `slices` in order, as if they were contiguous.
+    #[cfg(feature = "std")]
+    pub fn update_vectored(&mut self, slices: &[std::io::IoSlice<'_>]) {
+        for slice in slices {
+            self.update(slice);
+        }
+    }
+
+    /// Updates the digest with the bytes of `value` in the given byte order.
+    pub fn update_u16(&mut self, value: u16, endian: Endian) {
+        match endian {
+            Endian::Big => self.update(&value.to_be_bytes()),
+            Endian::Little => self.update(&value.to_le_bytes()),
+        }
+    }
+
+    /// Updates the digest with the bytes of `value` in the given byte order.
+    pub fn update_u32(&mut self, value: u32, endian: Endian) {
+        match endian {
+            Endian::Big => self.update(&value.to_be_bytes()),
+            Endian::Little => self.update(&value.to_le_bytes()),
+        }
+    }
+
+    /// Updates the digest with the bytes of `value` in the given byte order.
+    pub fn update_u64(&mut self, value: u64, endian: Endian) {
+        match endian {
+            Endian::Big => self.update(&value.to_be_bytes()),
+            Endian::Little => self.update(&value.to_le_bytes()),
+        }
+    }
+
+    /// Updates the digest with `words`, each in little-endian byte order.
+    pub fn update_words_le(&mut self, words: &[u32]) {
+        self.update_iter(words.iter().flat_map(|word| word.to_le_bytes()));
+    }
+
+    /// Updates the digest with `words`, each in big-endian byte order.
+    pub fn update_words_be(&mut self, words: &[u32]) {
+        self.update_iter(words.iter().flat_map(|word| word.to_be_bytes()));
+    }
+
+    /// Updates the digest with the bytes yielded by `bytes`.
+    pub fn update_iter(&mut self, bytes: impl IntoIterator<Item = u8>) {
+        let mut buf = [0u8; 64];
+        let mut n = 0;
+        for byte in bytes {
+            buf[n] = byte;
+            n += 1;
+            if n == buf.len() {
+                self.update(&buf);
+                n = 0;
+            }
+        }
+        self.update(&buf[..n]);
+    }
+
+    pub fn finalize(self) -> W {
+        W::finalize(self.crc.algorithm, self.value)
+    }
+
+    /// Like `finalize`, but returns a [`Checksum`] that knows the width of the algorithm.
+    pub fn finalize_typed(self) -> Checksum<W> {
+        Checksum {
+            value: W::finalize(self.crc.algorithm, self.value),
+            width: self.crc.algorithm.width,
+        }
+    }
+}
+
+impl<W: Register, I: Kernel<W>> Extend<u8> for Digest<'_, W, I> {
+    fn extend<T: IntoIterator<Item = u8>>(&mut self, bytes: T) {
+        self.update_iter(bytes);
+    }
+}
+
+impl<'b, W: Register, I: Kernel<W>> Extend<&'b u8> for Digest<'_, W, I> {
+    fn extend<T: IntoIterator<Item = &'b u8>>(&mut self, bytes: T) {
+        self.update_iter(bytes.into_iter().copied());
+    }
+}
@@ -0,0 +1,49 @@
+use crate::private::Build;
+use crate::*;
+use core::ops::Deref;
+use crc_catalog::Algorithm;
+use std::sync::OnceLock;
+
+/// A [`Crc`] whose lookup table is built on first use, e.g. to keep large tables
+/// out of the binary when the `Crc` is stored in a `static`.
+///
+/// The table is built on the stack of the first caller before it is moved into place, so
+/// prefer [`BoxedTable<L>`] for large tables, e.g. 64 KiB for `LazyCrc<u128, Table<16>>`.
+///
+/// ```rust
+/// use crc::{LazyCrc, Table, CRC_32_ISCSI};
+///
+/// static CRC: LazyCrc<u32, Table<16>> = LazyCrc::new(&CRC_32_ISCSI);
+///
+/// let mut digest = CRC.digest();
+/// digest.update(b"123456789");
+/// assert_eq!(digest.finalize(), CRC_32_ISCSI.check);
+/// ```
+pub struct LazyCrc<W: Width, I: Implementation = DefaultImpl> {
+    algorithm: &'static Algorithm<W>,
+    crc: OnceLock<Crc<W, I>>,
+}
+
+impl<W: Width, I: Implementation> LazyCrc<W, I> {
+    pub const fn new(algorithm: &'static Algorithm<W>) -> Self {
+        Self {
+            algorithm,
+            crc: OnceLock::new(),
+        }
+    }
+
+    pub const fn algorithm(&self) -> &'static Algorithm<W> {
+        self.algorithm
+    }
+}
+
+impl<W: Width, I: Build<W>> Deref for LazyCrc<W, I> {
+    type Target = Crc<W, I>;
+
+    fn deref(&self) -> &Crc<W, I> {
+        self.crc.get_or_init(|| Crc {
+            algorithm: self.algorithm,
+            data: I::build(self.algorithm),
+        })
+    }
+}
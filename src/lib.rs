@@ -25,47 +25,228 @@
 //! digest.update(b"123456789");
 //! assert_eq!(digest.finalize(), 0xaee7);
 //! ```
+//!
+//! Letting `AlgorithmBuilder` compute `check` and `residue` of a custom algorithm:
+//! ```rust
+//! const CUSTOM_ALG: crc::Algorithm<u16> = crc::AlgorithmBuilder::<u16>::new(16, 0x8005)
+//!     .init(0xffff)
+//!     .build();
+//! assert_eq!(CUSTOM_ALG.check, 0xaee7);
+//! ```
 #![no_std]
 #![forbid(unsafe_code)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 use core::fmt::Debug;
 
+pub use checksum::{Checksum, ChecksumBytes};
 pub use crc_catalog::algorithm::*;
 pub use crc_catalog::{Algorithm, Width};
+pub use kernel::validate;
+#[cfg(feature = "std")]
+pub use lazy::LazyCrc;
+pub use model::{AlgorithmExt, Model, ParseModelError};
+#[cfg(feature = "std")]
+pub use registry::{AnyAlgorithm, AnyCrc, AnyDigest, Registry};
 
+mod checksum;
 mod crc128;
 mod crc16;
 mod crc32;
 mod crc64;
 mod crc8;
+mod kernel;
+#[cfg(feature = "std")]
+mod lazy;
+mod model;
+pub mod peripheral;
+pub mod poly;
+#[cfg(feature = "std")]
+mod registry;
 mod table;
 mod util;
 
 /// A trait for CRC implementations.
 pub trait Implementation {
     /// Associated data necessary for the implementation (e.g. lookup tables).
-    type Data<W>;
+    ///
+    /// `W` is bounded by [`Width`] since 4.0, which lets [`SharedTable`] reference a
+    /// `'static` [`CrcTable`].
+    type Data<W: Width>;
 }
 
 /// A table-based implementation of the CRC algorithm, with `L` lanes.
 /// The number of entries in the lookup table is `L * 256`.
+///
+/// With `L > 1`, the input is processed `L` bytes at a time (slice-by-`L`),
+/// trading cache footprint for speed. Inputs shorter than `L` bytes, and the tail of
+/// longer ones, are processed one byte at a time using the first lane.
 #[derive(Copy, Clone)]
 pub struct Table<const L: usize> {}
 
 /// An implementation of the CRC algorithm with no lookup table.
 pub type NoTable = Table<0>;
 
+/// An implementation of the CRC algorithm with a 16-entry lookup table, processing
+/// 4 bits at a time.
+///
+/// This is a middle ground between [`NoTable`] and [`Table<1>`] in both size and speed,
+/// e.g. 64 bytes of table instead of 1 KiB for a `Crc<u32>`.
+#[derive(Copy, Clone)]
+pub struct NibbleTable {}
+
+/// An implementation of the CRC algorithm with no lookup table, which processes 8 bytes
+/// at a time using carry-less multiplication and Barrett reduction.
+///
+/// The carry-less products are computed with integer multiplications, so this is several
+/// times faster than [`NoTable`] on targets with a fast multiplier, while only storing
+/// two constants. Only widths of up to 64 bits are supported.
+#[derive(Copy, Clone)]
+pub struct Barrett {}
+
+/// An implementation of CRC-32 with no lookup table, which folds the input forward using
+/// a sparse multiple of the polynomial, as in the Chorba algorithm.
+///
+/// Each 64-bit word is eliminated with only shifts and XORs into the following words,
+/// and the last few words are reduced like [`Barrett`]. This is supported for the
+/// polynomials `0x04c11db7` (e.g. `CRC_32_ISO_HDLC`) and `0x1edc6f41` (`CRC_32_ISCSI`).
+///
+/// This is an order of magnitude faster than [`NoTable`], but only reaches between a
+/// quarter and a half of the throughput of `Table<16>`, depending on the CPU.
+#[derive(Copy, Clone)]
+pub struct Chorba {}
+
+/// A table-based implementation of the CRC algorithm for `u32` and `u64`, which splits
+/// the input into `N` interleaved braids of 64-bit words, as in zlib.
+///
+/// The braids are independent of each other until they are combined at the end, which
+/// lets out-of-order cores overlap their lookups. The lookup table has `9 * 256`
+/// entries, and `Braid<5>` is a good choice on most 64-bit machines.
+///
+/// At least one braid is required, which is checked at compile time:
+///
+/// ```rust,compile_fail
+/// use crc::{Braid, Crc, CRC_32_ISCSI};
+///
+/// let crc = Crc::<u32, Braid<0>>::try_new(&CRC_32_ISCSI);
+/// ```
+#[derive(Copy, Clone)]
+pub struct Braid<const N: usize> {}
+
+/// A table-based implementation like [`Table<L>`], which references a [`CrcTable`]
+/// instead of embedding its own copy of the lookup table.
+///
+/// Algorithms that only differ in `init`, `refout` and `xorout` can share a table:
+///
+/// ```rust
+/// use crc::{Crc, CrcTable, SharedTable, CRC_32_ISO_HDLC, CRC_32_JAMCRC};
+///
+/// static TABLE: CrcTable<u32, 16> = CrcTable::<u32, 16>::new(&CRC_32_ISO_HDLC);
+/// const HDLC: Crc<u32, SharedTable<16>> = Crc::<u32, SharedTable<16>>::with_table(&CRC_32_ISO_HDLC, &TABLE);
+/// const JAMCRC: Crc<u32, SharedTable<16>> = Crc::<u32, SharedTable<16>>::with_table(&CRC_32_JAMCRC, &TABLE);
+///
+/// assert_eq!(HDLC.checksum(b"123456789"), CRC_32_ISO_HDLC.check);
+/// assert_eq!(JAMCRC.checksum(b"123456789"), CRC_32_JAMCRC.check);
+/// ```
+#[derive(Copy, Clone)]
+pub struct SharedTable<const L: usize> {}
+
+/// A lookup table with `L` lanes for all algorithms with the same `width`, `poly` and
+/// `refin`, to be shared by several [`Crc`] instances via [`SharedTable<L>`].
+#[derive(Clone)]
+pub struct CrcTable<W, const L: usize> {
+    width: u8,
+    poly: W,
+    refin: bool,
+    table: [[W; 256]; L],
+}
+
+/// A table-based implementation like [`Table<L>`], whose lookup table is allocated
+/// on the heap and built at runtime rather than during const evaluation.
+///
+/// This keeps large tables (e.g. 64 KiB for `Crc<u128, BoxedTable<16>>`) off the
+/// stack when constructing or moving a `Crc`.
+#[cfg(feature = "alloc")]
+#[derive(Copy, Clone)]
+pub struct BoxedTable<const L: usize> {}
+
 type DefaultImpl = Table<1>;
 
+/// Number of messages processed in lockstep by `Crc::checksum_many`.
+const LANES: usize = 4;
+
+/// Folding routine of [`Chorba`], which updates the register with a sparse multiple of
+/// the polynomial.
+pub(crate) type Fold = fn(u32, bool, &[u64; 2], &[u8]) -> u32;
+
 impl<const L: usize> Implementation for Table<L> {
-    type Data<W> = [[W; 256]; L];
+    type Data<W: Width> = [[W; 256]; L];
+}
+
+#[cfg(feature = "alloc")]
+impl<const L: usize> Implementation for BoxedTable<L> {
+    type Data<W: Width> = alloc::boxed::Box<[[W; 256]; L]>;
+}
+
+impl Implementation for Barrett {
+    type Data<W: Width> = [u64; 2];
+}
+
+impl Implementation for Chorba {
+    /// The constants for the final Barrett reduction, and the folding routine for the
+    /// sparse multiple of the polynomial.
+    type Data<W: Width> = ([u64; 2], Fold);
+}
+
+impl<const N: usize> Implementation for Braid<N> {
+    type Data<W: Width> = [[W; 256]; 9];
+}
+
+impl Implementation for NibbleTable {
+    type Data<W: Width> = [W; 16];
+}
+
+impl<const L: usize> Implementation for SharedTable<L> {
+    type Data<W: Width> = &'static CrcTable<W, L>;
 }
 
 mod private {
+    use crc_catalog::Algorithm;
+
     pub trait Sealed {}
-    impl Sealed for super::Table<0> {}
-    impl Sealed for super::Table<1> {}
-    impl Sealed for super::Table<16> {}
+    impl<const L: usize> Sealed for super::Table<L> {}
+
+    /// Register operations of a CRC width, shared by all implementations.
+    pub trait Register:
+        super::Width + Copy + Default + PartialEq + core::ops::BitXor<Output = Self>
+    {
+        /// Converts an initial value into the register representation.
+        fn init(algorithm: &Algorithm<Self>, initial: Self) -> Self;
+        /// Converts the register into the final CRC value.
+        fn finalize(algorithm: &Algorithm<Self>, crc: Self) -> Self;
+        /// Advances the register through `n` zero bytes.
+        fn shift(algorithm: &Algorithm<Self>, crc: Self, n: usize) -> Self;
+        /// Checks that the algorithm is valid for this width.
+        fn validate(algorithm: &Algorithm<Self>) -> Result<(), super::AlgorithmError>;
+        /// Computes the checksum of `bytes` without a lookup table, for algorithms that are
+        /// not `'static`.
+        fn checksum(algorithm: &Algorithm<Self>, bytes: &[u8]) -> Self;
+    }
+
+    /// Runtime construction of the data of an implementation.
+    pub trait Build<W: super::Width>: super::Implementation {
+        fn build(algorithm: &Algorithm<W>) -> Self::Data<W>;
+    }
+
+    /// An implementation that is constructed at runtime, as opposed to the `const`
+    /// constructible [`Table<L>`](super::Table).
+    pub trait Kernel<W: Register>: super::Implementation {
+        fn update(crc: W, algorithm: &Algorithm<W>, data: &Self::Data<W>, bytes: &[u8]) -> W;
+    }
 }
 
 /// Crc instance with a specific width, algorithm, and implementation.
@@ -91,6 +272,100 @@ impl<W: Width + Debug, I: Implementation> Debug for Digest<'_, W, I> {
     }
 }
 
+/// Byte order in which integers are fed into a [`Digest`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Endian {
+    /// Most significant byte first.
+    Big,
+    /// Least significant byte first.
+    Little,
+}
+
+/// A `const` builder for custom [`Algorithm`]s, which derives `check` and `residue` from
+/// the other parameters.
+///
+/// ```rust
+/// use crc::{AlgorithmBuilder, Crc, CRC_16_UMTS};
+///
+/// const CUSTOM_ALG: crc::Algorithm<u16> = AlgorithmBuilder::<u16>::new(16, 0x8005).build();
+/// assert_eq!(CUSTOM_ALG, CRC_16_UMTS);
+///
+/// const MODBUS: crc::Algorithm<u16> = AlgorithmBuilder::<u16>::new(16, 0x8005)
+///     .init(0xffff)
+///     .refin(true)
+///     .refout(true)
+///     .build();
+/// assert_eq!(MODBUS.check, 0x4b37);
+/// assert_eq!(Crc::<u16>::new(&MODBUS).checksum(b"123456789"), 0x4b37);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct AlgorithmBuilder<W: Width> {
+    algorithm: Algorithm<W>,
+}
+
+/// An error returned when an [`Algorithm`] is not valid, see [`validate`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AlgorithmError {
+    /// `width` is zero or exceeds the number of bits of the register type.
+    Width,
+    /// `poly` has bits set above `width`.
+    Poly,
+    /// `init` has bits set above `width`.
+    Init,
+    /// `xorout` has bits set above `width`.
+    Xorout,
+    /// `check` has bits set above `width`.
+    Check,
+    /// `residue` has bits set above `width`.
+    Residue,
+    /// The checksum of `"123456789"` does not match `check`.
+    CheckMismatch,
+    /// The implementation does not support the algorithm, e.g. [`Chorba`] with another
+    /// polynomial.
+    Unsupported,
+    /// The [`CrcTable`] of a [`SharedTable`] was built for a different `width`, `poly`
+    /// or `refin`.
+    TableMismatch,
+}
+
+impl core::fmt::Display for AlgorithmError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            AlgorithmError::Width => "width is zero or too large for the register type",
+            AlgorithmError::Poly => "poly has bits set above width",
+            AlgorithmError::Init => "init has bits set above width",
+            AlgorithmError::Xorout => "xorout has bits set above width",
+            AlgorithmError::Check => "check has bits set above width",
+            AlgorithmError::Residue => "residue has bits set above width",
+            AlgorithmError::CheckMismatch => "checksum of \"123456789\" does not match check",
+            AlgorithmError::Unsupported => "algorithm is not supported by the implementation",
+            AlgorithmError::TableMismatch => "shared table was built for a different algorithm",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AlgorithmError {}
+
+/// Extension trait for feeding iterators of bytes into a [`Digest`].
+pub trait DigestIteratorExt: Iterator<Item = u8> + Sized {
+    /// Updates `digest` with all remaining bytes of the iterator.
+    ///
+    /// ```rust
+    /// use crc::{Crc, DigestIteratorExt, CRC_32_ISCSI};
+    ///
+    /// const CRC: Crc<u32> = Crc::<u32>::new(&CRC_32_ISCSI);
+    /// let mut digest = CRC.digest();
+    /// (b'1'..=b'9').update_digest(&mut digest);
+    /// assert_eq!(digest.finalize(), CRC.checksum(b"123456789"));
+    /// ```
+    fn update_digest<D: Extend<u8>>(self, digest: &mut D) {
+        digest.extend(self);
+    }
+}
+
+impl<T: Iterator<Item = u8>> DigestIteratorExt for T {}
+
 #[cfg(test)]
 mod test {
     use super::{Crc, CRC_32_ISCSI};
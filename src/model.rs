@@ -0,0 +1,173 @@
+use crate::private::Register;
+use crate::AlgorithmError;
+use core::fmt;
+use crc_catalog::Algorithm;
+
+const FIELDS: [&str; 9] = [
+    "width", "poly", "init", "refin", "refout", "xorout", "check", "residue", "name",
+];
+
+/// An [`Algorithm`] with an optional name, which is displayed as a model string of the
+/// CRC RevEng catalogue, e.g.
+/// `width=16 poly=0x1021 init=0xffff refin=false refout=false xorout=0x0000 check=0x29b1 residue=0x0000 name="CRC-16/IBM-3740"`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Model<'a, W: crc_catalog::Width> {
+    pub algorithm: Algorithm<W>,
+    pub name: Option<&'a str>,
+}
+
+/// An error returned when parsing a [`Model`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParseModelError {
+    /// A field is not of the form `key=value`, or a quoted value is not terminated.
+    Syntax,
+    /// A field has an unknown key.
+    UnknownField,
+    /// A field appears more than once.
+    DuplicateField(&'static str),
+    /// A required field is missing.
+    MissingField(&'static str),
+    /// A field has a value that is malformed or does not fit into the register type.
+    InvalidValue(&'static str),
+    /// The fields do not form a valid algorithm.
+    Algorithm(AlgorithmError),
+}
+
+impl fmt::Display for ParseModelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseModelError::Syntax => f.write_str("malformed model string"),
+            ParseModelError::UnknownField => f.write_str("unknown field in model string"),
+            ParseModelError::DuplicateField(key) => write!(f, "duplicate field `{key}`"),
+            ParseModelError::MissingField(key) => write!(f, "missing field `{key}`"),
+            ParseModelError::InvalidValue(key) => write!(f, "invalid value for `{key}`"),
+            ParseModelError::Algorithm(err) => err.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseModelError {}
+
+impl<'a, W: Register + Into<u128> + TryFrom<u128>> Model<'a, W> {
+    /// Parses a model string with fields in any order, where `name` is optional.
+    pub fn parse(src: &'a str) -> Result<Self, ParseModelError> {
+        let mut values: [Option<&'a str>; FIELDS.len()] = [None; FIELDS.len()];
+        let mut rest = src.trim_start();
+        while !rest.is_empty() {
+            let (key, value) = rest.split_once('=').ok_or(ParseModelError::Syntax)?;
+            let field = FIELDS
+                .iter()
+                .position(|field| *field == key)
+                .ok_or(ParseModelError::UnknownField)?;
+            let (value, tail) = match value.strip_prefix('"') {
+                Some(quoted) => quoted.split_once('"').ok_or(ParseModelError::Syntax)?,
+                None => value.split_at(value.find(char::is_whitespace).unwrap_or(value.len())),
+            };
+            if !tail.is_empty() && !tail.starts_with(char::is_whitespace) {
+                return Err(ParseModelError::Syntax);
+            }
+            if values[field].replace(value).is_some() {
+                return Err(ParseModelError::DuplicateField(FIELDS[field]));
+            }
+            rest = tail.trim_start();
+        }
+
+        let value =
+            |field: usize| values[field].ok_or(ParseModelError::MissingField(FIELDS[field]));
+        let width = value(0)?
+            .parse::<u8>()
+            .map_err(|_| ParseModelError::InvalidValue(FIELDS[0]))?;
+        let algorithm = Algorithm {
+            width,
+            poly: parse_int(value(1)?, 1)?,
+            init: parse_int(value(2)?, 2)?,
+            refin: parse_bool(value(3)?, 3)?,
+            refout: parse_bool(value(4)?, 4)?,
+            xorout: parse_int(value(5)?, 5)?,
+            check: parse_int(value(6)?, 6)?,
+            residue: parse_int(value(7)?, 7)?,
+        };
+        W::validate(&algorithm).map_err(ParseModelError::Algorithm)?;
+        Ok(Model {
+            algorithm,
+            name: values[8],
+        })
+    }
+}
+
+/// Parses `0x`-prefixed hex or decimal.
+fn parse_int<W: TryFrom<u128>>(value: &str, field: usize) -> Result<W, ParseModelError> {
+    let value = match value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+    {
+        Some(hex) => u128::from_str_radix(hex, 16),
+        None => value.parse(),
+    };
+    value
+        .ok()
+        .and_then(|value| W::try_from(value).ok())
+        .ok_or(ParseModelError::InvalidValue(FIELDS[field]))
+}
+
+fn parse_bool(value: &str, field: usize) -> Result<bool, ParseModelError> {
+    value
+        .parse()
+        .map_err(|_| ParseModelError::InvalidValue(FIELDS[field]))
+}
+
+impl<W: Register + Into<u128>> fmt::Display for Model<'_, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let algorithm = &self.algorithm;
+        let [poly, init, xorout, check, residue]: [u128; 5] = [
+            algorithm.poly.into(),
+            algorithm.init.into(),
+            algorithm.xorout.into(),
+            algorithm.check.into(),
+            algorithm.residue.into(),
+        ];
+        let width = "0x".len() + (algorithm.width as usize).div_ceil(4);
+        write!(
+            f,
+            "width={} poly={poly:#0width$x} init={init:#0width$x} refin={} refout={} \
+             xorout={xorout:#0width$x} check={check:#0width$x} residue={residue:#0width$x}",
+            algorithm.width, algorithm.refin, algorithm.refout,
+        )?;
+        if let Some(name) = self.name {
+            write!(f, " name=\"{name}\"")?;
+        }
+        Ok(())
+    }
+}
+
+/// Extension trait for converting an [`Algorithm`] from and to model strings.
+///
+/// ```rust
+/// use crc::{Algorithm, AlgorithmExt, CRC_16_IBM_3740};
+///
+/// let model = "width=16 poly=0x1021 init=0xffff refin=false refout=false xorout=0x0000 check=0x29b1 residue=0x0000 name=\"CRC-16/IBM-3740\"";
+/// let algorithm = Algorithm::<u16>::from_model_str(model).unwrap();
+/// assert_eq!(algorithm, CRC_16_IBM_3740);
+/// assert_eq!(algorithm.model(Some("CRC-16/IBM-3740")).to_string(), model);
+/// ```
+pub trait AlgorithmExt<W: crc_catalog::Width>: Sized {
+    /// Parses a model string, ignoring its name, see [`Model::parse`].
+    fn from_model_str(src: &str) -> Result<Self, ParseModelError>;
+
+    /// Returns the algorithm with the given name, to be displayed as a model string.
+    fn model<'a>(&self, name: Option<&'a str>) -> Model<'a, W>;
+}
+
+impl<W: Register + Into<u128> + TryFrom<u128>> AlgorithmExt<W> for Algorithm<W> {
+    fn from_model_str(src: &str) -> Result<Self, ParseModelError> {
+        Model::parse(src).map(|model| model.algorithm)
+    }
+
+    fn model<'a>(&self, name: Option<&'a str>) -> Model<'a, W> {
+        Model {
+            algorithm: *self,
+            name,
+        }
+    }
+}
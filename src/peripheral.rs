@@ -0,0 +1,257 @@
+//! Host-side emulation of microcontroller CRC peripherals.
+//!
+//! [`CrcUnit`] models the programmable CRC calculation unit found on STM32 (and many
+//! similar) microcontrollers, so that firmware can be tested off-target against the
+//! exact values the on-chip block produces for a given register configuration.
+//!
+//! ```rust
+//! use crc::peripheral::{Config, CrcUnit};
+//! use crc::{Crc, CRC_32_MPEG_2};
+//!
+//! let mut unit = CrcUnit::new(Config::STM32);
+//! unit.write_u32(0x31323334);
+//! unit.write_u32(0x35363738);
+//! assert_eq!(unit.read(), Crc::<u32>::new(&CRC_32_MPEG_2).checksum(b"12345678"));
+//! ```
+
+use crate::table::crc32_table;
+
+/// Size of the generator polynomial (`POLYSIZE`).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PolySize {
+    Bits7,
+    Bits8,
+    Bits16,
+    Bits32,
+}
+
+impl PolySize {
+    /// Width of the CRC register in bits.
+    pub const fn width(self) -> u8 {
+        match self {
+            PolySize::Bits7 => 7,
+            PolySize::Bits8 => 8,
+            PolySize::Bits16 => 16,
+            PolySize::Bits32 => 32,
+        }
+    }
+}
+
+/// Bit reversal applied to data written to the unit (`REV_IN`).
+///
+/// The reversal unit never exceeds the size of the write itself, e.g. an 8-bit write
+/// with `HalfWord` reversal is reversed within its byte.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum InputReversal {
+    None,
+    Byte,
+    HalfWord,
+    Word,
+}
+
+/// Register configuration of a [`CrcUnit`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Config {
+    /// Generator polynomial without the leading term (`CRC_POL`).
+    pub poly: u32,
+    /// Size of the polynomial and the CRC register (`POLYSIZE`).
+    pub poly_size: PolySize,
+    /// Value loaded into the register on reset (`CRC_INIT`).
+    pub init: u32,
+    /// Reversal of input data (`REV_IN`).
+    pub rev_in: InputReversal,
+    /// Reversal of the register when read, within the polynomial size (`REV_OUT`).
+    pub rev_out: bool,
+}
+
+impl Config {
+    /// Reset configuration of the STM32 CRC unit, equivalent to `CRC_32_MPEG_2` over
+    /// big-endian words.
+    pub const STM32: Config = Config {
+        poly: 0x04c11db7,
+        poly_size: PolySize::Bits32,
+        init: 0xffffffff,
+        rev_in: InputReversal::None,
+        rev_out: false,
+    };
+}
+
+/// Emulated CRC calculation unit.
+#[derive(Clone)]
+pub struct CrcUnit {
+    config: Config,
+    table: [[u32; 256]; 1],
+    value: u32,
+}
+
+impl CrcUnit {
+    /// Creates a unit with the given configuration and its register reset to `init`.
+    pub const fn new(config: Config) -> Self {
+        let width = config.poly_size.width();
+        Self {
+            config,
+            table: crc32_table(width, config.poly, false),
+            value: config.init << (32 - width),
+        }
+    }
+
+    pub const fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Reloads `init` into the register (`CR.RESET`).
+    pub const fn reset(&mut self) {
+        self.value = self.config.init << (32 - self.config.poly_size.width());
+    }
+
+    /// Writes 8 bits to the data register.
+    pub const fn write_u8(&mut self, data: u8) {
+        let data = match self.config.rev_in {
+            InputReversal::None => data,
+            _ => data.reverse_bits(),
+        };
+        self.feed(data);
+    }
+
+    /// Writes 16 bits to the data register.
+    pub const fn write_u16(&mut self, data: u16) {
+        let data = match self.config.rev_in {
+            InputReversal::None => data,
+            InputReversal::Byte => u16::from_be_bytes(reverse_bytes(data.to_be_bytes())),
+            InputReversal::HalfWord | InputReversal::Word => data.reverse_bits(),
+        };
+        let bytes = data.to_be_bytes();
+        self.feed(bytes[0]);
+        self.feed(bytes[1]);
+    }
+
+    /// Writes 32 bits to the data register.
+    pub const fn write_u32(&mut self, data: u32) {
+        let data = match self.config.rev_in {
+            InputReversal::None => data,
+            InputReversal::Byte => u32::from_be_bytes(reverse_bytes(data.to_be_bytes())),
+            InputReversal::HalfWord => data.reverse_bits().rotate_left(16),
+            InputReversal::Word => data.reverse_bits(),
+        };
+        let bytes = data.to_be_bytes();
+        self.feed(bytes[0]);
+        self.feed(bytes[1]);
+        self.feed(bytes[2]);
+        self.feed(bytes[3]);
+    }
+
+    /// Reads the data register.
+    pub const fn read(&self) -> u32 {
+        let width = self.config.poly_size.width();
+        if self.config.rev_out {
+            self.value.reverse_bits()
+        } else {
+            self.value >> (32 - width)
+        }
+    }
+
+    /// Shifts one byte into the register, most significant bit first.
+    const fn feed(&mut self, byte: u8) {
+        let index = ((self.value >> 24) as u8 ^ byte) as usize;
+        self.value = self.table[0][index] ^ (self.value << 8);
+    }
+}
+
+/// Reverses the bits within each byte.
+const fn reverse_bytes<const N: usize>(mut bytes: [u8; N]) -> [u8; N] {
+    let mut i = 0;
+    while i < N {
+        bytes[i] = bytes[i].reverse_bits();
+        i += 1;
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn matches_catalog() {
+        const DATA: &[u8] = b"12345678";
+
+        let mut unit = CrcUnit::new(Config::STM32);
+        for word in DATA.chunks(4) {
+            unit.write_u32(u32::from_be_bytes(word.try_into().unwrap()));
+        }
+        assert_eq!(unit.read(), Crc::<u32>::new(&CRC_32_MPEG_2).checksum(DATA));
+
+        // Reflected CRC-32 as configured by e.g. zlib-compatible firmware
+        let hdlc = Crc::<u32>::new(&CRC_32_ISO_HDLC).checksum(DATA);
+        let reflected = Config {
+            rev_in: InputReversal::Byte,
+            rev_out: true,
+            ..Config::STM32
+        };
+        let mut unit = CrcUnit::new(reflected);
+        for byte in DATA {
+            unit.write_u8(*byte);
+        }
+        assert_eq!(unit.read() ^ 0xffffffff, hdlc);
+
+        let mut unit = CrcUnit::new(Config {
+            rev_in: InputReversal::HalfWord,
+            ..reflected
+        });
+        for half in DATA.chunks(2) {
+            unit.write_u16(u16::from_le_bytes(half.try_into().unwrap()));
+        }
+        assert_eq!(unit.read() ^ 0xffffffff, hdlc);
+
+        let mut unit = CrcUnit::new(Config {
+            rev_in: InputReversal::Word,
+            ..reflected
+        });
+        for word in DATA.chunks(4) {
+            unit.write_u32(u32::from_le_bytes(word.try_into().unwrap()));
+        }
+        assert_eq!(unit.read() ^ 0xffffffff, hdlc);
+
+        let mut unit = CrcUnit::new(reflected);
+        unit.write_u16(u16::from_be_bytes(*b"12"));
+        unit.write_u32(u32::from_be_bytes(*b"3456"));
+        unit.write_u16(u16::from_be_bytes(*b"78"));
+        assert_eq!(unit.read() ^ 0xffffffff, hdlc);
+
+        // Narrow polynomials
+        let mut unit = CrcUnit::new(Config {
+            poly: 0x1021,
+            poly_size: PolySize::Bits16,
+            init: 0,
+            rev_in: InputReversal::None,
+            rev_out: false,
+        });
+        DATA.iter().for_each(|byte| unit.write_u8(*byte));
+        assert_eq!(
+            unit.read(),
+            Crc::<u16>::new(&CRC_16_XMODEM).checksum(DATA) as u32
+        );
+
+        let mut unit = CrcUnit::new(Config {
+            poly: 0x09,
+            poly_size: PolySize::Bits7,
+            init: 0,
+            rev_in: InputReversal::None,
+            rev_out: false,
+        });
+        DATA.iter().for_each(|byte| unit.write_u8(*byte));
+        assert_eq!(
+            unit.read(),
+            Crc::<u8>::new(&CRC_7_MMC).checksum(DATA) as u32
+        );
+
+        unit.reset();
+        unit.write_u32(u32::from_be_bytes(*b"1234"));
+        unit.write_u32(u32::from_be_bytes(*b"5678"));
+        assert_eq!(
+            unit.read(),
+            Crc::<u8>::new(&CRC_7_MMC).checksum(DATA) as u32
+        );
+    }
+}
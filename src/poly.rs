@@ -0,0 +1,1136 @@
+//! Polynomial arithmetic over GF(2).
+//!
+//! [`Poly`] exposes the primitives used internally to shift and combine CRCs, e.g. for
+//! combining checksums, forging messages or designing LFSRs. Modular operations take a
+//! generator in the same form as an [`Algorithm`], i.e. its `width` (the degree) and its
+//! `poly` without the leading term:
+//!
+//! ```rust
+//! use crc::poly::Poly;
+//! use crc::CRC_32_ISCSI;
+//!
+//! let (width, poly) = (CRC_32_ISCSI.width, CRC_32_ISCSI.poly);
+//! // x^32 is congruent to the generator without its leading term
+//! assert_eq!(Poly::<u32>::xpow_mod(32, width, poly), Poly(poly));
+//! // (x + 1)^2 = x^2 + 1
+//! assert_eq!(Poly(0b11u8).clmul(Poly(0b11)), (Poly(0b101), Poly(0)));
+//! ```
+
+use crate::util;
+use crc_catalog::Algorithm;
+
+/// A polynomial over GF(2), where bit `i` holds the coefficient of `x^i`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Poly<W>(pub W);
+
+/// An irreducible factor `x^width + poly` of a generator, in the form of an [`Algorithm`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Factor<W> {
+    pub width: u8,
+    pub poly: W,
+    /// Number of times the factor divides the generator.
+    pub multiplicity: u8,
+}
+
+/// The irreducible factors of a generator, ordered by degree.
+///
+/// ```rust
+/// use crc::poly::Poly;
+///
+/// assert_eq!(Poly::<u8>::factor(4, 0x04).to_string(), "x^2 * (x + 1)^2");
+/// assert_eq!(Poly::<u8>::factor(8, 0x07).to_string(), "(x + 1) * (x^7 + x^6 + x^5 + x^4 + x^3 + x^2 + 1)");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct Factors<W> {
+    factors: [Factor<W>; 128],
+    len: usize,
+}
+
+impl<W> Factors<W> {
+    pub const fn as_slice(&self) -> &[Factor<W>] {
+        self.factors.split_at(self.len).0
+    }
+}
+
+impl<W: core::fmt::Debug> core::fmt::Debug for Factors<W> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+/// Algebraic properties of the generator polynomial of an [`Algorithm`], which determine
+/// the error detection guarantees of a CRC.
+///
+/// ```rust
+/// use crc::poly::Properties;
+/// use crc::{CRC_16_IBM_SDLC, CRC_32_ISO_HDLC};
+///
+/// let hdlc = Properties::<u32>::of(&CRC_32_ISO_HDLC);
+/// assert!(hdlc.primitive);
+/// assert_eq!(hdlc.period, Some(0xffffffff));
+///
+/// // (x + 1) times a primitive polynomial, which detects all odd numbers of bit errors
+/// let x25 = Properties::<u16>::of(&CRC_16_IBM_SDLC);
+/// assert!(!x25.irreducible);
+/// assert_eq!(x25.period, Some(0x7fff));
+/// assert_eq!(
+///     x25.factors.to_string(),
+///     "(x + 1) * (x^15 + x^14 + x^13 + x^12 + x^4 + x^3 + x^2 + x + 1)"
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Properties<W> {
+    pub irreducible: bool,
+    pub primitive: bool,
+    /// Smallest `n` such that the generator divides `x^n + 1`, if not divisible by `x`.
+    pub period: Option<u128>,
+    pub factors: Factors<W>,
+}
+
+/// Notations of a generator polynomial `x^width + ... + 1` as an integer, shown for the
+/// generator of `CRC_32_ISO_HDLC`.
+///
+/// ```rust
+/// use crc::poly::{Notation, Poly};
+/// use crc::CRC_32_ISO_HDLC;
+///
+/// let poly = Poly::<u32>::parse("0x82608edb", 32, Notation::Koopman).unwrap();
+/// assert_eq!(poly, CRC_32_ISO_HDLC.poly);
+/// assert_eq!(Poly::<u32>::format(32, poly, Notation::Reversed).to_string(), "0xedb88320");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Notation {
+    /// Most significant bit first without the `x^width` term, as in [`Algorithm::poly`]
+    /// (`0x04c11db7`).
+    Normal,
+    /// Least significant bit first without the `x^width` term, as used by implementations
+    /// of reflected algorithms (`0xedb88320`).
+    Reversed,
+    /// Most significant bit first without the `+ 1` term, as in Koopman's tables
+    /// (`0x82608edb`).
+    Koopman,
+    /// Normal notation of the reciprocal polynomial `x^width * G(1/x)` (`0xdb710641`).
+    Reciprocal,
+}
+
+/// An error returned when parsing a generator with [`Poly::parse`] or a checksum with
+/// [`Checksum::from_hex`](crate::Checksum::from_hex).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParsePolyError {
+    /// The string contains no hex digits.
+    Empty,
+    /// The string contains a character that is not a hex digit.
+    InvalidDigit,
+    /// The value does not fit into `width` bits.
+    Overflow,
+}
+
+impl core::fmt::Display for ParsePolyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            ParsePolyError::Empty => "cannot parse hex from empty string",
+            ParsePolyError::InvalidDigit => "invalid hex digit found in string",
+            ParsePolyError::Overflow => "value too large for width",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParsePolyError {}
+
+/// A generator formatted as zero-padded hex in some [`Notation`].
+#[derive(Copy, Clone, Debug)]
+pub struct Notated<W> {
+    value: W,
+    width: u8,
+}
+
+macro_rules! impl_poly {
+    ($($w:ty),*) => {$(
+        impl Poly<$w> {
+            /// Degree of the polynomial, or `None` for the zero polynomial.
+            pub const fn degree(self) -> Option<u32> {
+                match self.0 {
+                    0 => None,
+                    value => Some(<$w>::BITS - 1 - value.leading_zeros()),
+                }
+            }
+
+            /// Reverses the order of the `width` lowest coefficients, e.g. to convert a
+            /// polynomial between normal and reflected form.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `width` is zero or exceeds the bit width of the type.
+            pub const fn reverse(self, width: u8) -> Self {
+                assert_width(width, <$w>::BITS);
+                Poly(reverse(self.0 as u128, width) as $w)
+            }
+
+            /// Carry-less product of `self` and `rhs`, returned as its low and high halves.
+            pub const fn clmul(self, rhs: Self) -> (Self, Self) {
+                let (lo, hi) = util::clmul128(self.0 as u128, rhs.0 as u128);
+                let hi = if <$w>::BITS == 128 {
+                    hi
+                } else {
+                    lo.wrapping_shr(<$w>::BITS)
+                };
+                (Poly(lo as $w), Poly(hi as $w))
+            }
+
+            /// Divides `self` by `rhs`, returning the quotient and the remainder.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `rhs` is zero.
+            pub const fn div_rem(self, rhs: Self) -> (Self, Self) {
+                assert!(rhs.0 != 0, "division by the zero polynomial");
+                let (quotient, rem) = util::divrem(self.0 as u128, rhs.0 as u128);
+                (Poly(quotient as $w), Poly(rem as $w))
+            }
+
+            /// Greatest common divisor of `self` and `rhs`.
+            pub const fn gcd(self, rhs: Self) -> Self {
+                Poly(util::gcd(self.0 as u128, rhs.0 as u128) as $w)
+            }
+
+            /// Reduces `self` modulo the generator `x^width + poly`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `width` is zero or exceeds the bit width of the type.
+            pub const fn reduce(self, width: u8, poly: $w) -> Self {
+                assert_width(width, <$w>::BITS);
+                Poly(util::reduce(self.0 as u128, poly as u128, width) as $w)
+            }
+
+            /// Multiplies `self` by `rhs` modulo the generator `x^width + poly`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `width` is zero or exceeds the bit width of the type.
+            pub const fn mul_mod(self, rhs: Self, width: u8, poly: $w) -> Self {
+                let a = self.reduce(width, poly).0 as u128;
+                let b = rhs.reduce(width, poly).0 as u128;
+                Poly(util::mulmod(a, b, poly as u128, width) as $w)
+            }
+
+            /// Raises `self` to the `n`-th power modulo the generator `x^width + poly`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `width` is zero or exceeds the bit width of the type.
+            pub const fn pow_mod(self, n: u128, width: u8, poly: $w) -> Self {
+                let a = self.reduce(width, poly).0 as u128;
+                Poly(util::powmod(a, n, poly as u128, width) as $w)
+            }
+
+            /// Computes `x^n` modulo the generator `x^width + poly`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `width` is zero or exceeds the bit width of the type.
+            pub const fn xpow_mod(n: u128, width: u8, poly: $w) -> Self {
+                assert_width(width, <$w>::BITS);
+                let x = util::mulx(1, poly as u128, width);
+                Poly(util::powmod(x, n, poly as u128, width) as $w)
+            }
+
+            /// Converts the initial register `init` of the augmented algorithm, which
+            /// appends `width` zero bits to the message, into the direct initial value of
+            /// [`Algorithm::init`], i.e. multiplies it by `x^width` modulo the generator.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `width` is zero or exceeds the bit width of the type.
+            pub const fn direct_init(width: u8, poly: $w, init: $w) -> $w {
+                assert_width(width, <$w>::BITS);
+                let init = util::reduce(init as u128, poly as u128, width);
+                util::mulmod(init, poly as u128, poly as u128, width) as $w
+            }
+
+            /// Converts the direct initial value `init` of [`Algorithm::init`] into the
+            /// initial register of the augmented algorithm, the inverse of
+            /// [`Poly::direct_init`].
+            ///
+            /// # Panics
+            ///
+            /// Panics if `width` is zero or exceeds the bit width of the type, or if the
+            /// generator lacks a `+ 1` term, so that `x^width` is not invertible.
+            pub const fn augmented_init(width: u8, poly: $w, init: $w) -> $w {
+                assert_width(width, <$w>::BITS);
+                assert!(poly & 1 == 1, "generator has no + 1 term");
+                let mut value = util::reduce(init as u128, poly as u128, width);
+                let mut i = 0;
+                while i < width {
+                    value = if value & 1 == 1 {
+                        ((value ^ poly as u128) >> 1) | (1 << (width - 1))
+                    } else {
+                        value >> 1
+                    };
+                    i += 1;
+                }
+                value as $w
+            }
+
+            /// Returns whether the generator `x^width + poly` is irreducible.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `width` is zero or exceeds the bit width of the type.
+            pub const fn is_irreducible(width: u8, poly: $w) -> bool {
+                assert_width(width, <$w>::BITS);
+                is_irreducible(width, poly as u128)
+            }
+
+            /// Returns whether the generator `x^width + poly` is primitive, i.e. irreducible
+            /// with a period of `2^width - 1`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `width` is zero or exceeds the bit width of the type.
+            pub const fn is_primitive(width: u8, poly: $w) -> bool {
+                assert_width(width, <$w>::BITS);
+                poly & 1 == 1
+                    && is_irreducible(width, poly as u128)
+                    && order(width, poly as u128) == u128::MAX >> (128 - width as u32)
+            }
+
+            /// Period of the generator `x^width + poly`, i.e. the smallest `n` such that it
+            /// divides `x^n + 1`, or `None` if it is divisible by `x`.
+            ///
+            /// A CRC detects all 2-bit errors in codewords of at most `period` bits.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `width` is zero or exceeds the bit width of the type.
+            pub const fn period(width: u8, poly: $w) -> Option<u128> {
+                assert_width(width, <$w>::BITS);
+                period(&factor(width, poly as u128))
+            }
+
+            /// Factors the generator `x^width + poly` into irreducible generators.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `width` is zero or exceeds the bit width of the type.
+            pub const fn factor(width: u8, poly: $w) -> Factors<$w> {
+                assert_width(width, <$w>::BITS);
+                Factors::<$w>::new(&factor(width, poly as u128))
+            }
+        }
+
+        impl Poly<$w> {
+            /// Converts the generator `x^width + poly` into the given notation.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `width` is zero or exceeds the bit width of the type, or if the
+            /// notation needs a `+ 1` term that the generator lacks.
+            pub const fn to_notation(width: u8, poly: $w, notation: Notation) -> $w {
+                assert_width(width, <$w>::BITS);
+                let poly = poly as u128;
+                let value = match notation {
+                    Notation::Normal => poly,
+                    Notation::Reversed => reverse(poly, width),
+                    Notation::Koopman => {
+                        assert!(poly & 1 == 1, "generator has no + 1 term");
+                        (poly >> 1) | (1 << (width - 1))
+                    }
+                    Notation::Reciprocal => {
+                        assert!(poly & 1 == 1, "generator has no + 1 term");
+                        reciprocal(poly, width)
+                    }
+                };
+                value as $w
+            }
+
+            /// Converts a generator of degree `width` from the given notation into the
+            /// normal notation of [`Algorithm::poly`].
+            ///
+            /// # Panics
+            ///
+            /// Panics if `width` is zero or exceeds the bit width of the type, or if a
+            /// reciprocal polynomial lacks its `+ 1` term.
+            pub const fn from_notation(width: u8, value: $w, notation: Notation) -> $w {
+                assert_width(width, <$w>::BITS);
+                let value = value as u128;
+                let poly = match notation {
+                    Notation::Normal => value,
+                    Notation::Reversed => reverse(value, width),
+                    Notation::Koopman => ((value << 1) | 1) & mask(width),
+                    Notation::Reciprocal => {
+                        assert!(value & 1 == 1, "reciprocal has no + 1 term");
+                        reciprocal(value, width)
+                    }
+                };
+                poly as $w
+            }
+
+            /// Parses a generator of degree `width` in the given notation from hex, with
+            /// an optional `0x` prefix, and returns it in normal notation.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `width` is zero or exceeds the bit width of the type.
+            pub const fn parse(
+                src: &str,
+                width: u8,
+                notation: Notation,
+            ) -> Result<$w, ParsePolyError> {
+                assert_width(width, <$w>::BITS);
+                match parse_hex(src, width) {
+                    Ok(value) => Ok(Self::from_notation(width, value as $w, notation)),
+                    Err(err) => Err(err),
+                }
+            }
+
+            /// Formats the generator `x^width + poly` as hex in the given notation.
+            ///
+            /// # Panics
+            ///
+            /// Panics like [`Poly::to_notation`].
+            pub const fn format(width: u8, poly: $w, notation: Notation) -> Notated<$w> {
+                Notated {
+                    value: Self::to_notation(width, poly, notation),
+                    width,
+                }
+            }
+        }
+
+        impl core::fmt::Display for Notated<$w> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let digits = (self.width as usize).div_ceil(4);
+                write!(f, "0x{:0digits$x}", self.value)
+            }
+        }
+
+        impl Factors<$w> {
+            const fn new(raw: &RawFactors) -> Self {
+                let mut factors = [Factor {
+                    width: 0,
+                    poly: 0,
+                    multiplicity: 0,
+                }; 128];
+                let mut i = 0;
+                while i < raw.len {
+                    let (width, poly, multiplicity) = raw.factors[i];
+                    factors[i] = Factor {
+                        width,
+                        poly: poly as $w,
+                        multiplicity,
+                    };
+                    i += 1;
+                }
+                Self {
+                    factors,
+                    len: raw.len,
+                }
+            }
+        }
+
+        impl Properties<$w> {
+            /// Computes the properties of the generator of `algorithm`.
+            pub const fn of(algorithm: &Algorithm<$w>) -> Self {
+                let (width, poly) = (algorithm.width, algorithm.poly as u128);
+                assert_width(width, <$w>::BITS);
+                let raw = factor(width, poly);
+                let period = period(&raw);
+                let irreducible = raw.len == 1 && raw.factors[0].2 == 1;
+                let primitive = irreducible
+                    && matches!(period, Some(period) if period == u128::MAX >> (128 - width as u32));
+                Self {
+                    irreducible,
+                    primitive,
+                    period,
+                    factors: Factors::<$w>::new(&raw),
+                }
+            }
+        }
+
+        impl core::fmt::Display for Factor<$w> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let terms = (self.poly as u128).count_ones() + 1;
+                let parens = terms > 1 && self.multiplicity > 1;
+                if parens {
+                    f.write_str("(")?;
+                }
+                write_term(f, self.width as u32)?;
+                let mut i = self.width as u32;
+                while i > 0 {
+                    i -= 1;
+                    if (self.poly >> i) & 1 == 1 {
+                        f.write_str(" + ")?;
+                        write_term(f, i)?;
+                    }
+                }
+                if parens {
+                    f.write_str(")")?;
+                }
+                if self.multiplicity > 1 {
+                    write!(f, "^{}", self.multiplicity)?;
+                }
+                Ok(())
+            }
+        }
+
+        impl core::fmt::Display for Factors<$w> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                for (i, factor) in self.as_slice().iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" * ")?;
+                    }
+                    if self.len > 1 && factor.poly != 0 && factor.multiplicity == 1 {
+                        write!(f, "({factor})")?;
+                    } else {
+                        write!(f, "{factor}")?;
+                    }
+                }
+                Ok(())
+            }
+        }
+    )*};
+}
+
+impl_poly!(u8, u16, u32, u64, u128);
+
+const fn assert_width(width: u8, bits: u32) {
+    assert!(width != 0 && width as u32 <= bits, "width out of range");
+}
+
+const fn mask(width: u8) -> u128 {
+    u128::MAX >> (128 - width as u32)
+}
+
+/// Reverses the `width` lowest bits of `value`.
+const fn reverse(value: u128, width: u8) -> u128 {
+    value.reverse_bits() >> (128 - width as u32)
+}
+
+/// Normal notation of the reciprocal of the generator `x^width + poly`, whose `+ 1` term
+/// becomes the implicit leading term.
+const fn reciprocal(poly: u128, width: u8) -> u128 {
+    ((reverse(poly, width) << 1) | 1) & mask(width)
+}
+
+/// Parses an optionally `0x`-prefixed hex number of at most `width` bits.
+pub(crate) const fn parse_hex(src: &str, width: u8) -> Result<u128, ParsePolyError> {
+    let mut digits = src.as_bytes();
+    if let [b'0', b'x' | b'X', rest @ ..] = digits {
+        digits = rest;
+    }
+    if digits.is_empty() {
+        return Err(ParsePolyError::Empty);
+    }
+    let mut value: u128 = 0;
+    let mut i = 0;
+    while i < digits.len() {
+        let digit = match digits[i] {
+            b'0'..=b'9' => digits[i] - b'0',
+            b'a'..=b'f' => digits[i] - b'a' + 10,
+            b'A'..=b'F' => digits[i] - b'A' + 10,
+            _ => return Err(ParsePolyError::InvalidDigit),
+        };
+        if value >> 124 != 0 {
+            return Err(ParsePolyError::Overflow);
+        }
+        value = (value << 4) | digit as u128;
+        i += 1;
+    }
+    if value & !mask(width) != 0 {
+        return Err(ParsePolyError::Overflow);
+    }
+    Ok(value)
+}
+
+fn write_term(f: &mut core::fmt::Formatter<'_>, exponent: u32) -> core::fmt::Result {
+    match exponent {
+        0 => f.write_str("1"),
+        1 => f.write_str("x"),
+        _ => write!(f, "x^{exponent}"),
+    }
+}
+
+/// Irreducible factors as `(width, poly, multiplicity)`, sorted by `width` and `poly`.
+struct RawFactors {
+    factors: [(u8, u128, u8); 128],
+    len: usize,
+}
+
+/// Degree of the nonzero polynomial `a` with explicit leading term.
+const fn degree(a: u128) -> u32 {
+    127 - a.leading_zeros()
+}
+
+/// Converts the polynomial `a` with explicit leading term into a generator.
+const fn generator(a: u128) -> (u8, u128) {
+    let degree = degree(a);
+    (degree as u8, a ^ (1 << degree))
+}
+
+/// Divides the generator `x^width + poly` by the polynomial `b` of degree at least one,
+/// returning the quotient and the remainder.
+const fn divrem_generator(width: u8, poly: u128, b: u128) -> (u128, u128) {
+    let degree = degree(b);
+    let low = b ^ (1 << degree);
+    let mask = u128::MAX >> (128 - degree);
+    let mut quotient = 0;
+    let mut rem = 0;
+    let mut i = width as u32 + 1;
+    while i > 0 {
+        i -= 1;
+        let bit = if i == width as u32 {
+            1
+        } else {
+            (poly >> i) & 1
+        };
+        let top = (rem >> (degree - 1)) & 1;
+        rem = (((rem << 1) | bit) & mask) ^ (top * low);
+        quotient = (quotient << 1) | top;
+    }
+    (quotient, rem)
+}
+
+/// Greatest common divisor of the generator `x^width + poly` and the nonzero polynomial
+/// `a` of lower degree.
+const fn gcd_generator(width: u8, poly: u128, a: u128) -> u128 {
+    if a == 1 {
+        return 1;
+    }
+    util::gcd(a, divrem_generator(width, poly, a).1)
+}
+
+/// Computes `x^(2^k)` modulo the generator `x^width + poly`.
+const fn xpow2k(k: u32, width: u8, poly: u128) -> u128 {
+    let mut power = util::mulx(1, poly, width);
+    let mut i = 0;
+    while i < k {
+        power = util::mulmod(power, power, poly, width);
+        i += 1;
+    }
+    power
+}
+
+/// Rabin's test: the generator is irreducible iff it divides `x^(2^width) - x`, but is
+/// coprime to `x^(2^(width/q)) - x` for every prime `q` dividing `width`.
+const fn is_irreducible(width: u8, poly: u128) -> bool {
+    if poly & 1 == 0 {
+        return width == 1;
+    }
+    let x = util::mulx(1, poly, width);
+    if xpow2k(width as u32, width, poly) != x {
+        return false;
+    }
+    let mut q = 2;
+    while q <= width {
+        if width % q == 0 && is_prime(q) {
+            let a = xpow2k((width / q) as u32, width, poly) ^ x;
+            if a == 0 || gcd_generator(width, poly, a) != 1 {
+                return false;
+            }
+        }
+        q += 1;
+    }
+    true
+}
+
+const fn is_prime(n: u8) -> bool {
+    let mut d = 2;
+    while d * d <= n {
+        if n % d == 0 {
+            return false;
+        }
+        d += 1;
+    }
+    n >= 2
+}
+
+/// Order of `x` modulo the irreducible generator `x^width + poly` not divisible by `x`,
+/// which divides `2^width - 1`.
+const fn order(width: u8, poly: u128) -> u128 {
+    let x = util::mulx(1, poly, width);
+    let mut order = u128::MAX >> (128 - width as u32);
+    let mut d = 1;
+    while d <= width {
+        if width % d == 0 {
+            let primes = PRIMITIVE_FACTORS[d as usize - 1];
+            let mut i = 0;
+            while i < primes.len() {
+                let p = primes[i];
+                while order % p == 0 && util::powmod(x, order / p, poly, width) == 1 {
+                    order /= p;
+                }
+                i += 1;
+            }
+        }
+        d += 1;
+    }
+    order
+}
+
+/// The period of a generator is the least common multiple of the orders of its
+/// irreducible factors, times the least power of two not below their multiplicities.
+const fn period(raw: &RawFactors) -> Option<u128> {
+    let mut period = 1;
+    let mut multiplicity = 1;
+    let mut i = 0;
+    while i < raw.len {
+        let (width, poly, m) = raw.factors[i];
+        if poly & 1 == 0 {
+            return None;
+        }
+        let order = order(width, poly);
+        period = period / util::gcd_int(period, order) * order;
+        if m > multiplicity {
+            multiplicity = m;
+        }
+        i += 1;
+    }
+    Some(period << (multiplicity as u32).next_power_of_two().trailing_zeros())
+}
+
+/// Factors the generator `x^width + poly` by repeatedly splitting off a nontrivial
+/// factor until all remaining factors are irreducible.
+const fn factor(width: u8, poly: u128) -> RawFactors {
+    let mut raw = RawFactors {
+        factors: [(0, 0, 0); 128],
+        len: 0,
+    };
+    let mut pending = [(0, 0); 128];
+    pending[0] = (width, poly);
+    let mut pending_len = 1;
+    while pending_len > 0 {
+        pending_len -= 1;
+        let (width, poly) = pending[pending_len];
+        if is_irreducible(width, poly) {
+            // Insert in order, merging repeated factors
+            let mut i = 0;
+            while i < raw.len
+                && (raw.factors[i].0 < width
+                    || raw.factors[i].0 == width && raw.factors[i].1 < poly)
+            {
+                i += 1;
+            }
+            if i < raw.len && raw.factors[i].0 == width && raw.factors[i].1 == poly {
+                raw.factors[i].2 += 1;
+            } else {
+                let mut j = raw.len;
+                while j > i {
+                    raw.factors[j] = raw.factors[j - 1];
+                    j -= 1;
+                }
+                raw.factors[i] = (width, poly, 1);
+                raw.len += 1;
+            }
+        } else {
+            let a = split(width, poly);
+            let b = divrem_generator(width, poly, a).0;
+            pending[pending_len] = generator(a);
+            pending[pending_len + 1] = generator(b);
+            pending_len += 2;
+        }
+    }
+    raw
+}
+
+/// Finds a nontrivial factor of the reducible generator `x^width + poly`.
+const fn split(width: u8, poly: u128) -> u128 {
+    if poly & 1 == 0 {
+        return 0b10;
+    }
+
+    // The derivative vanishes for squares, otherwise its gcd with the generator contains
+    // the repeated factors
+    let mut derivative = (poly >> 1) & 0x5555_5555_5555_5555_5555_5555_5555_5555;
+    if width % 2 == 1 {
+        derivative |= 1 << (width - 1);
+    }
+    if derivative == 0 {
+        let mut root = 1 << (width / 2);
+        let mut i = 0;
+        while i < width / 2 {
+            root |= ((poly >> (2 * i)) & 1) << i;
+            i += 1;
+        }
+        return root;
+    }
+    let g = gcd_generator(width, poly, derivative);
+    if g != 1 {
+        return g;
+    }
+
+    // Distinct-degree factorization: the gcd with x^(2^d) - x is the product of all
+    // irreducible factors whose degree divides d
+    let x = util::mulx(1, poly, width);
+    let mut power = x;
+    let mut d = 1;
+    loop {
+        power = util::mulmod(power, power, poly, width);
+        if power != x {
+            let g = gcd_generator(width, poly, power ^ x);
+            if g != 1 {
+                return g;
+            }
+        } else {
+            break;
+        }
+        d += 1;
+    }
+
+    // Equal-degree factorization: all factors have degree d, and the trace map
+    // a + a^2 + ... + a^(2^(d-1)) is 0 or 1 modulo each of them. It is not constant for
+    // some power of x, as they span all residues.
+    let mut a = 1;
+    loop {
+        a = util::mulx(a, poly, width);
+        let mut trace = a;
+        let mut square = a;
+        let mut i = 1;
+        while i < d {
+            square = util::mulmod(square, square, poly, width);
+            trace ^= square;
+            i += 1;
+        }
+        if trace > 1 {
+            let g = gcd_generator(width, poly, trace);
+            if g != 1 {
+                return g;
+            }
+        }
+    }
+}
+
+/// Prime factors of `2^d - 1` that do not divide `2^k - 1` for any `k < d`, at index
+/// `d - 1`. The prime factors of `2^d - 1` are those of all divisors of `d`.
+#[rustfmt::skip]
+const PRIMITIVE_FACTORS: [&[u128]; 128] = [
+    &[],
+    &[3],
+    &[7],
+    &[5],
+    &[31],
+    &[],
+    &[127],
+    &[17],
+    &[73],
+    &[11],
+    &[23, 89],
+    &[13],
+    &[8191],
+    &[43],
+    &[151],
+    &[257],
+    &[131071],
+    &[19],
+    &[524287],
+    &[41],
+    &[337],
+    &[683],
+    &[47, 178481],
+    &[241],
+    &[601, 1801],
+    &[2731],
+    &[262657],
+    &[29, 113],
+    &[233, 1103, 2089],
+    &[331],
+    &[2147483647],
+    &[65537],
+    &[599479],
+    &[43691],
+    &[71, 122921],
+    &[37, 109],
+    &[223, 616318177],
+    &[174763],
+    &[79, 121369],
+    &[61681],
+    &[13367, 164511353],
+    &[5419],
+    &[431, 9719, 2099863],
+    &[397, 2113],
+    &[631, 23311],
+    &[2796203],
+    &[2351, 4513, 13264529],
+    &[97, 673],
+    &[4432676798593],
+    &[251, 4051],
+    &[103, 2143, 11119],
+    &[53, 157, 1613],
+    &[6361, 69431, 20394401],
+    &[87211],
+    &[881, 3191, 201961],
+    &[15790321],
+    &[32377, 1212847],
+    &[59, 3033169],
+    &[179951, 3203431780337],
+    &[61, 1321],
+    &[2305843009213693951],
+    &[715827883],
+    &[92737, 649657],
+    &[641, 6700417],
+    &[145295143558111],
+    &[67, 20857],
+    &[193707721, 761838257287],
+    &[137, 953, 26317],
+    &[10052678938039],
+    &[281, 86171],
+    &[228479, 48544121, 212885833],
+    &[433, 38737],
+    &[439, 2298041, 9361973132609],
+    &[1777, 25781083],
+    &[100801, 10567201],
+    &[229, 457, 525313],
+    &[581283643249112959],
+    &[22366891],
+    &[2687, 202029703, 1113491139767],
+    &[4278255361],
+    &[2593, 71119, 97685839],
+    &[83, 8831418697],
+    &[167, 57912614113275649087721],
+    &[1429, 14449],
+    &[9520972806333758431],
+    &[2932031007403],
+    &[4177, 9857737155463],
+    &[353, 2931542417],
+    &[618970019642690137449562111],
+    &[18837001],
+    &[911, 112901153, 23140471537],
+    &[277, 1013, 1657, 30269],
+    &[658812288653553079],
+    &[283, 165768537521],
+    &[191, 420778751, 30327152671],
+    &[193, 22253377],
+    &[11447, 13842607235828485645766393],
+    &[4363953127297],
+    &[199, 153649, 33057806959],
+    &[101, 8101, 268501],
+    &[7432339208719, 341117531003194129],
+    &[307, 2857, 6529],
+    &[2550183799, 3976656429941438590393],
+    &[858001, 308761441],
+    &[29191, 106681, 152041],
+    &[107, 28059810762433],
+    &[162259276829213363391578010288127],
+    &[246241, 279073],
+    &[745988807, 870035986098720987332873],
+    &[2971, 48912491],
+    &[321679, 26295457, 319020217],
+    &[5153, 54410972897],
+    &[3391, 23279, 65993, 1868569, 1066818132868207],
+    &[571, 160465489],
+    &[14951, 4036961, 2646507710984041],
+    &[107367629, 536903681],
+    &[937, 6553, 86113, 7830118297],
+    &[2833, 37171, 1824726041],
+    &[239, 20231, 62983048367, 131105292137],
+    &[4562284561],
+    &[727, 1786393878363164227858270210279],
+    &[768614336404564651],
+    &[3887047, 177722253954175633],
+    &[5581, 8681, 49477, 384773],
+    &[269089806001, 4710883168879506001],
+    &[77158673929],
+    &[170141183460469231731687303715884105727],
+    &[274177, 67280421310721],
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(Poly(0u32).degree(), None);
+        assert_eq!(Poly(1u32).degree(), Some(0));
+        assert_eq!(Poly(u128::MAX).degree(), Some(127));
+        assert_eq!(Poly(0x8005u16).reverse(16), Poly(0xa001));
+        assert_eq!(Poly(0x07u8).reverse(7), Poly(0x70));
+
+        // Division inverts multiplication
+        let a = Poly(0x1234_5678_9abc_def0u64);
+        let b = Poly(0x0000_0001_04c1_1db7u64);
+        let (lo, hi) = a.clmul(b);
+        assert_eq!((lo, hi), (Poly(0x8f77a65dd942e1d0), Poly(0x127103c9)));
+        let (quotient, rem) = lo.div_rem(b);
+        assert_eq!(
+            Poly(0x1234u16).div_rem(Poly(0x100)),
+            (Poly(0x12), Poly(0x34))
+        );
+        assert_eq!(quotient.clmul(b).0 .0 ^ rem.0, lo.0);
+        assert!(rem.degree() < b.degree());
+
+        let (lo, hi) = Poly(u128::MAX).clmul(Poly(3));
+        assert_eq!((lo, hi), (Poly(1), Poly(1)));
+
+        // (x + 1)^3 against x (x + 1)^3 and (x + 1) (x^2 + x + 1)
+        assert_eq!(Poly(0b1111u8).gcd(Poly(0b11110)), Poly(0b1111));
+        assert_eq!(Poly(0b1111u8).gcd(Poly(0b1001)), Poly(0b11));
+        assert_eq!(Poly(0b111u8).gcd(Poly(0b1011)), Poly(1));
+    }
+
+    #[test]
+    fn modular() {
+        let (width, poly) = (CRC_32_ISCSI.width, CRC_32_ISCSI.poly);
+        let x = Poly(2u32);
+        assert_eq!(Poly::<u32>::xpow_mod(0, width, poly), Poly(1));
+        assert_eq!(Poly::<u32>::xpow_mod(31, width, poly), Poly(1 << 31));
+        assert_eq!(Poly::<u32>::xpow_mod(32, width, poly), Poly(poly));
+        assert_eq!(
+            x.pow_mod(100, width, poly),
+            Poly::<u32>::xpow_mod(100, width, poly)
+        );
+        assert_eq!(
+            Poly::<u32>::xpow_mod(40, width, poly).mul_mod(
+                Poly::<u32>::xpow_mod(60, width, poly),
+                width,
+                poly
+            ),
+            Poly::<u32>::xpow_mod(100, width, poly)
+        );
+        assert_eq!(Poly(0x107u32).reduce(8, 0x07), Poly(0));
+        assert_eq!(Poly(0x1ffu32).reduce(8, 0x07), Poly(0xf8));
+
+        // The generator of CRC-32 is primitive, so the order of x is 2^32 - 1
+        let poly = CRC_32_ISO_HDLC.poly;
+        assert_eq!(Poly::<u32>::xpow_mod((1 << 32) - 1, width, poly), Poly(1));
+
+        // The CRC of a message without init and xorout is the message times x^width
+        let (width, poly) = (CRC_64_ECMA_182.width, CRC_64_ECMA_182.poly);
+        let mut bytes = [0; 12];
+        bytes[0] = 0x5a;
+        let expected = Crc::<u64>::new(&CRC_64_ECMA_182).checksum(&bytes);
+        let power = Poly::<u64>::xpow_mod(8 * 11 + 64, width, poly);
+        assert_eq!(Poly(0x5au64).mul_mod(power, width, poly), Poly(expected));
+    }
+
+    #[test]
+    fn properties() {
+        assert!(Poly::<u32>::is_primitive(32, CRC_32_ISO_HDLC.poly));
+        assert!(Poly::<u64>::is_primitive(64, 0x1b));
+        assert!(Poly::<u128>::is_primitive(128, 0x87));
+        assert!(Poly::<u8>::is_primitive(1, 1));
+        assert!(Poly::<u8>::is_irreducible(1, 0));
+        assert!(!Poly::<u8>::is_primitive(1, 0));
+        // x^4 + x^3 + x^2 + x + 1 is irreducible with period 5
+        assert!(Poly::<u8>::is_irreducible(4, 0x0f));
+        assert!(!Poly::<u8>::is_primitive(4, 0x0f));
+        assert_eq!(Poly::<u8>::period(4, 0x0f), Some(5));
+        assert_eq!(Poly::<u8>::period(8, 0x06), None);
+
+        // (x + 1)^2 times irreducible factors of degree 15, 15, 15 and 17
+        let xz = Properties::<u64>::of(&CRC_64_XZ);
+        assert!(!xz.irreducible && !xz.primitive);
+        let degrees = xz
+            .factors
+            .as_slice()
+            .iter()
+            .map(|f| (f.width, f.multiplicity));
+        assert!(degrees.eq([(1, 2), (15, 1), (15, 1), (15, 1), (17, 1)]));
+        assert_eq!(xz.period, Poly::<u64>::period(64, CRC_64_XZ.poly));
+        assert_eq!(xz.period.unwrap() % 2, 0);
+
+        for (width, poly) in [(8, 0x07), (16, 0x8005), (16, 0x1021), (32, 0x1edc6f41)] {
+            let factors = Poly::<u32>::factor(width, poly);
+            let [one, rest] = factors.as_slice() else {
+                panic!("{factors}");
+            };
+            assert_eq!((one.width, one.poly, one.multiplicity), (1, 1, 1));
+            assert!(Poly::<u32>::is_primitive(rest.width, rest.poly));
+            assert_eq!(
+                Poly::<u32>::period(width, poly),
+                Some((1 << (width - 1)) - 1)
+            );
+        }
+
+        let factors = Poly::<u128>::factor(82, CRC_82_DARC.poly);
+        let mut product = Poly(1u128);
+        for factor in factors.as_slice() {
+            let f = Poly(factor.poly | 1 << factor.width);
+            for _ in 0..factor.multiplicity {
+                product = product.clmul(f).0;
+            }
+        }
+        assert_eq!(product, Poly(CRC_82_DARC.poly | 1 << 82));
+        assert_eq!(Poly(0x0fu8).gcd(Poly(0x1f)), Poly(1));
+    }
+
+    #[test]
+    fn notation() {
+        // From the table of CRC polynomials on Wikipedia
+        let forms = [
+            (32, 0x04c11db7, 0xedb88320, 0x82608edb, 0xdb710641),
+            (16, 0x8005, 0xa001, 0xc002, 0x4003),
+            (16, 0x1021, 0x8408, 0x8810, 0x0811),
+            (8, 0x07, 0xe0, 0x83, 0xc1),
+            (5, 0x05, 0x14, 0x12, 0x09),
+        ];
+        for (width, normal, reversed, koopman, reciprocal) in forms {
+            for (notation, value) in [
+                (Notation::Normal, normal),
+                (Notation::Reversed, reversed),
+                (Notation::Koopman, koopman),
+                (Notation::Reciprocal, reciprocal),
+            ] {
+                assert_eq!(Poly::<u32>::to_notation(width, normal, notation), value);
+                assert_eq!(Poly::<u32>::from_notation(width, value, notation), normal);
+            }
+        }
+
+        let poly = CRC_82_DARC.poly;
+        for notation in [Notation::Reversed, Notation::Koopman, Notation::Reciprocal] {
+            let value = Poly::<u128>::to_notation(82, poly, notation);
+            assert_eq!(Poly::<u128>::from_notation(82, value, notation), poly);
+        }
+        let koopman = Poly::<u128>::to_notation(128, 0x87, Notation::Koopman);
+        assert_eq!(koopman, 1 << 127 | 0x43);
+
+        assert_eq!(
+            Poly::<u16>::parse("0x8408", 16, Notation::Reversed),
+            Ok(0x1021)
+        );
+        assert_eq!(
+            Poly::<u16>::parse("C002", 16, Notation::Koopman),
+            Ok(0x8005)
+        );
+        assert_eq!(Poly::<u8>::parse("0x7", 8, Notation::Normal), Ok(0x07));
+        assert_eq!(
+            Poly::<u8>::parse("0x", 8, Notation::Normal),
+            Err(ParsePolyError::Empty)
+        );
+        assert_eq!(
+            Poly::<u8>::parse("0x1g", 8, Notation::Normal),
+            Err(ParsePolyError::InvalidDigit)
+        );
+        assert_eq!(
+            Poly::<u8>::parse("0x20", 5, Notation::Normal),
+            Err(ParsePolyError::Overflow)
+        );
+        assert_eq!(
+            Poly::<u128>::parse("0x1ffffffffffffffffffffffffffffffff", 128, Notation::Normal),
+            Err(ParsePolyError::Overflow)
+        );
+    }
+
+    #[test]
+    fn init() {
+        // The augmented CRC-CCITT with init 0xffff is CRC-16/SPI-FUJITSU
+        assert_eq!(Poly::<u16>::direct_init(16, 0x1021, 0xffff), 0x1d0f);
+        assert_eq!(Poly::<u16>::augmented_init(16, 0x1021, 0x1d0f), 0xffff);
+        assert_eq!(Poly::<u16>::augmented_init(16, 0x1021, 0xffff), 0x84cf);
+        assert_eq!(Poly::<u16>::direct_init(16, 0x1021, 0), 0);
+
+        for (width, poly) in [(3, 0x3), (12, 0x80f), (32, 0x04c11db7), (64, 0x1b)] {
+            let mask = u64::MAX >> (64 - width);
+            for init in [1, 0x5a5a_5a5a_5a5a_5a5a & mask, mask] {
+                let direct = Poly::<u64>::direct_init(width as u8, poly, init);
+                assert_eq!(Poly::<u64>::augmented_init(width as u8, poly, direct), init);
+            }
+        }
+        let direct = Poly::<u128>::direct_init(82, CRC_82_DARC.poly, 1);
+        assert_eq!(direct, CRC_82_DARC.poly);
+        let direct = Poly::<u128>::direct_init(128, 0x87, 0x3);
+        assert_eq!(Poly::<u128>::augmented_init(128, 0x87, direct), 0x3);
+        assert_eq!(Poly::<u8>::direct_init(8, 0x06, 0x01), 0x06);
+    }
+}
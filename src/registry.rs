@@ -0,0 +1,464 @@
+use crate::private::{Build, Register};
+use crate::*;
+use core::any::Any;
+use crc_catalog::Algorithm;
+use std::borrow::ToOwned;
+use std::boxed::Box;
+use std::collections::BTreeMap;
+use std::string::String;
+use std::sync::{Mutex, PoisonError};
+use std::vec::Vec;
+
+/// All algorithms of the catalogue, under their names in the CRC RevEng catalogue.
+const CATALOG: [(&str, AnyAlgorithm); 113] = [
+    ("CRC-3/GSM", AnyAlgorithm::U8(&CRC_3_GSM)),
+    ("CRC-3/ROHC", AnyAlgorithm::U8(&CRC_3_ROHC)),
+    ("CRC-4/G-704", AnyAlgorithm::U8(&CRC_4_G_704)),
+    ("CRC-4/INTERLAKEN", AnyAlgorithm::U8(&CRC_4_INTERLAKEN)),
+    ("CRC-5/EPC-C1G2", AnyAlgorithm::U8(&CRC_5_EPC_C1G2)),
+    ("CRC-5/G-704", AnyAlgorithm::U8(&CRC_5_G_704)),
+    ("CRC-5/USB", AnyAlgorithm::U8(&CRC_5_USB)),
+    ("CRC-6/CDMA2000-A", AnyAlgorithm::U8(&CRC_6_CDMA2000_A)),
+    ("CRC-6/CDMA2000-B", AnyAlgorithm::U8(&CRC_6_CDMA2000_B)),
+    ("CRC-6/DARC", AnyAlgorithm::U8(&CRC_6_DARC)),
+    ("CRC-6/G-704", AnyAlgorithm::U8(&CRC_6_G_704)),
+    ("CRC-6/GSM", AnyAlgorithm::U8(&CRC_6_GSM)),
+    ("CRC-7/MMC", AnyAlgorithm::U8(&CRC_7_MMC)),
+    ("CRC-7/ROHC", AnyAlgorithm::U8(&CRC_7_ROHC)),
+    ("CRC-7/UMTS", AnyAlgorithm::U8(&CRC_7_UMTS)),
+    ("CRC-8/AUTOSAR", AnyAlgorithm::U8(&CRC_8_AUTOSAR)),
+    ("CRC-8/BLUETOOTH", AnyAlgorithm::U8(&CRC_8_BLUETOOTH)),
+    ("CRC-8/CDMA2000", AnyAlgorithm::U8(&CRC_8_CDMA2000)),
+    ("CRC-8/DARC", AnyAlgorithm::U8(&CRC_8_DARC)),
+    ("CRC-8/DVB-S2", AnyAlgorithm::U8(&CRC_8_DVB_S2)),
+    ("CRC-8/GSM-A", AnyAlgorithm::U8(&CRC_8_GSM_A)),
+    ("CRC-8/GSM-B", AnyAlgorithm::U8(&CRC_8_GSM_B)),
+    ("CRC-8/HITAG", AnyAlgorithm::U8(&CRC_8_HITAG)),
+    ("CRC-8/I-432-1", AnyAlgorithm::U8(&CRC_8_I_432_1)),
+    ("CRC-8/I-CODE", AnyAlgorithm::U8(&CRC_8_I_CODE)),
+    ("CRC-8/LTE", AnyAlgorithm::U8(&CRC_8_LTE)),
+    ("CRC-8/MAXIM-DOW", AnyAlgorithm::U8(&CRC_8_MAXIM_DOW)),
+    ("CRC-8/MIFARE-MAD", AnyAlgorithm::U8(&CRC_8_MIFARE_MAD)),
+    ("CRC-8/NRSC-5", AnyAlgorithm::U8(&CRC_8_NRSC_5)),
+    ("CRC-8/OPENSAFETY", AnyAlgorithm::U8(&CRC_8_OPENSAFETY)),
+    ("CRC-8/ROHC", AnyAlgorithm::U8(&CRC_8_ROHC)),
+    ("CRC-8/SAE-J1850", AnyAlgorithm::U8(&CRC_8_SAE_J1850)),
+    ("CRC-8/SMBUS", AnyAlgorithm::U8(&CRC_8_SMBUS)),
+    ("CRC-8/TECH-3250", AnyAlgorithm::U8(&CRC_8_TECH_3250)),
+    ("CRC-8/WCDMA", AnyAlgorithm::U8(&CRC_8_WCDMA)),
+    ("CRC-10/ATM", AnyAlgorithm::U16(&CRC_10_ATM)),
+    ("CRC-10/CDMA2000", AnyAlgorithm::U16(&CRC_10_CDMA2000)),
+    ("CRC-10/GSM", AnyAlgorithm::U16(&CRC_10_GSM)),
+    ("CRC-11/FLEXRAY", AnyAlgorithm::U16(&CRC_11_FLEXRAY)),
+    ("CRC-11/UMTS", AnyAlgorithm::U16(&CRC_11_UMTS)),
+    ("CRC-12/CDMA2000", AnyAlgorithm::U16(&CRC_12_CDMA2000)),
+    ("CRC-12/DECT", AnyAlgorithm::U16(&CRC_12_DECT)),
+    ("CRC-12/GSM", AnyAlgorithm::U16(&CRC_12_GSM)),
+    ("CRC-12/UMTS", AnyAlgorithm::U16(&CRC_12_UMTS)),
+    ("CRC-13/BBC", AnyAlgorithm::U16(&CRC_13_BBC)),
+    ("CRC-14/DARC", AnyAlgorithm::U16(&CRC_14_DARC)),
+    ("CRC-14/GSM", AnyAlgorithm::U16(&CRC_14_GSM)),
+    ("CRC-15/CAN", AnyAlgorithm::U16(&CRC_15_CAN)),
+    ("CRC-15/MPT1327", AnyAlgorithm::U16(&CRC_15_MPT1327)),
+    ("CRC-16/ARC", AnyAlgorithm::U16(&CRC_16_ARC)),
+    ("CRC-16/CDMA2000", AnyAlgorithm::U16(&CRC_16_CDMA2000)),
+    ("CRC-16/CMS", AnyAlgorithm::U16(&CRC_16_CMS)),
+    ("CRC-16/DDS-110", AnyAlgorithm::U16(&CRC_16_DDS_110)),
+    ("CRC-16/DECT-R", AnyAlgorithm::U16(&CRC_16_DECT_R)),
+    ("CRC-16/DECT-X", AnyAlgorithm::U16(&CRC_16_DECT_X)),
+    ("CRC-16/DNP", AnyAlgorithm::U16(&CRC_16_DNP)),
+    ("CRC-16/EN-13757", AnyAlgorithm::U16(&CRC_16_EN_13757)),
+    ("CRC-16/GENIBUS", AnyAlgorithm::U16(&CRC_16_GENIBUS)),
+    ("CRC-16/GSM", AnyAlgorithm::U16(&CRC_16_GSM)),
+    ("CRC-16/IBM-3740", AnyAlgorithm::U16(&CRC_16_IBM_3740)),
+    ("CRC-16/IBM-SDLC", AnyAlgorithm::U16(&CRC_16_IBM_SDLC)),
+    (
+        "CRC-16/ISO-IEC-14443-3-A",
+        AnyAlgorithm::U16(&CRC_16_ISO_IEC_14443_3_A),
+    ),
+    ("CRC-16/KERMIT", AnyAlgorithm::U16(&CRC_16_KERMIT)),
+    ("CRC-16/LJ1200", AnyAlgorithm::U16(&CRC_16_LJ1200)),
+    ("CRC-16/M17", AnyAlgorithm::U16(&CRC_16_M17)),
+    ("CRC-16/MAXIM-DOW", AnyAlgorithm::U16(&CRC_16_MAXIM_DOW)),
+    ("CRC-16/MCRF4XX", AnyAlgorithm::U16(&CRC_16_MCRF4XX)),
+    ("CRC-16/MODBUS", AnyAlgorithm::U16(&CRC_16_MODBUS)),
+    ("CRC-16/NRSC-5", AnyAlgorithm::U16(&CRC_16_NRSC_5)),
+    (
+        "CRC-16/OPENSAFETY-A",
+        AnyAlgorithm::U16(&CRC_16_OPENSAFETY_A),
+    ),
+    (
+        "CRC-16/OPENSAFETY-B",
+        AnyAlgorithm::U16(&CRC_16_OPENSAFETY_B),
+    ),
+    ("CRC-16/PROFIBUS", AnyAlgorithm::U16(&CRC_16_PROFIBUS)),
+    ("CRC-16/RIELLO", AnyAlgorithm::U16(&CRC_16_RIELLO)),
+    ("CRC-16/SPI-FUJITSU", AnyAlgorithm::U16(&CRC_16_SPI_FUJITSU)),
+    ("CRC-16/T10-DIF", AnyAlgorithm::U16(&CRC_16_T10_DIF)),
+    ("CRC-16/TELEDISK", AnyAlgorithm::U16(&CRC_16_TELEDISK)),
+    ("CRC-16/TMS37157", AnyAlgorithm::U16(&CRC_16_TMS37157)),
+    ("CRC-16/UMTS", AnyAlgorithm::U16(&CRC_16_UMTS)),
+    ("CRC-16/USB", AnyAlgorithm::U16(&CRC_16_USB)),
+    ("CRC-16/XMODEM", AnyAlgorithm::U16(&CRC_16_XMODEM)),
+    ("CRC-17/CAN-FD", AnyAlgorithm::U32(&CRC_17_CAN_FD)),
+    ("CRC-21/CAN-FD", AnyAlgorithm::U32(&CRC_21_CAN_FD)),
+    ("CRC-24/BLE", AnyAlgorithm::U32(&CRC_24_BLE)),
+    ("CRC-24/FLEXRAY-A", AnyAlgorithm::U32(&CRC_24_FLEXRAY_A)),
+    ("CRC-24/FLEXRAY-B", AnyAlgorithm::U32(&CRC_24_FLEXRAY_B)),
+    ("CRC-24/INTERLAKEN", AnyAlgorithm::U32(&CRC_24_INTERLAKEN)),
+    ("CRC-24/LTE-A", AnyAlgorithm::U32(&CRC_24_LTE_A)),
+    ("CRC-24/LTE-B", AnyAlgorithm::U32(&CRC_24_LTE_B)),
+    ("CRC-24/OPENPGP", AnyAlgorithm::U32(&CRC_24_OPENPGP)),
+    ("CRC-24/OS-9", AnyAlgorithm::U32(&CRC_24_OS_9)),
+    ("CRC-30/CDMA", AnyAlgorithm::U32(&CRC_30_CDMA)),
+    ("CRC-31/PHILIPS", AnyAlgorithm::U32(&CRC_31_PHILIPS)),
+    ("CRC-32/AIXM", AnyAlgorithm::U32(&CRC_32_AIXM)),
+    ("CRC-32/AUTOSAR", AnyAlgorithm::U32(&CRC_32_AUTOSAR)),
+    ("CRC-32/BASE91-D", AnyAlgorithm::U32(&CRC_32_BASE91_D)),
+    ("CRC-32/BZIP2", AnyAlgorithm::U32(&CRC_32_BZIP2)),
+    ("CRC-32/CD-ROM-EDC", AnyAlgorithm::U32(&CRC_32_CD_ROM_EDC)),
+    ("CRC-32/CKSUM", AnyAlgorithm::U32(&CRC_32_CKSUM)),
+    ("CRC-32/ISCSI", AnyAlgorithm::U32(&CRC_32_ISCSI)),
+    ("CRC-32/ISO-HDLC", AnyAlgorithm::U32(&CRC_32_ISO_HDLC)),
+    ("CRC-32/JAMCRC", AnyAlgorithm::U32(&CRC_32_JAMCRC)),
+    ("CRC-32/MEF", AnyAlgorithm::U32(&CRC_32_MEF)),
+    ("CRC-32/MPEG-2", AnyAlgorithm::U32(&CRC_32_MPEG_2)),
+    ("CRC-32/XFER", AnyAlgorithm::U32(&CRC_32_XFER)),
+    ("CRC-40/GSM", AnyAlgorithm::U64(&CRC_40_GSM)),
+    ("CRC-64/ECMA-182", AnyAlgorithm::U64(&CRC_64_ECMA_182)),
+    ("CRC-64/GO-ISO", AnyAlgorithm::U64(&CRC_64_GO_ISO)),
+    ("CRC-64/MS", AnyAlgorithm::U64(&CRC_64_MS)),
+    ("CRC-64/NVME", AnyAlgorithm::U64(&CRC_64_NVME)),
+    ("CRC-64/REDIS", AnyAlgorithm::U64(&CRC_64_REDIS)),
+    ("CRC-64/WE", AnyAlgorithm::U64(&CRC_64_WE)),
+    ("CRC-64/XZ", AnyAlgorithm::U64(&CRC_64_XZ)),
+    ("CRC-82/DARC", AnyAlgorithm::U128(&CRC_82_DARC)),
+];
+
+/// A reference to an [`Algorithm`] of any width.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AnyAlgorithm {
+    U8(&'static Algorithm<u8>),
+    U16(&'static Algorithm<u16>),
+    U32(&'static Algorithm<u32>),
+    U64(&'static Algorithm<u64>),
+    U128(&'static Algorithm<u128>),
+}
+
+impl AnyAlgorithm {
+    pub const fn width(&self) -> u8 {
+        match self {
+            AnyAlgorithm::U8(algorithm) => algorithm.width,
+            AnyAlgorithm::U16(algorithm) => algorithm.width,
+            AnyAlgorithm::U32(algorithm) => algorithm.width,
+            AnyAlgorithm::U64(algorithm) => algorithm.width,
+            AnyAlgorithm::U128(algorithm) => algorithm.width,
+        }
+    }
+
+    /// Returns the algorithm if its register type is `W`.
+    pub fn downcast<W: Width + 'static>(&self) -> Option<&'static Algorithm<W>> {
+        let algorithm: &'static dyn Any = match *self {
+            AnyAlgorithm::U8(algorithm) => algorithm,
+            AnyAlgorithm::U16(algorithm) => algorithm,
+            AnyAlgorithm::U32(algorithm) => algorithm,
+            AnyAlgorithm::U64(algorithm) => algorithm,
+            AnyAlgorithm::U128(algorithm) => algorithm,
+        };
+        algorithm.downcast_ref()
+    }
+}
+
+impl From<&'static Algorithm<u8>> for AnyAlgorithm {
+    fn from(algorithm: &'static Algorithm<u8>) -> Self {
+        AnyAlgorithm::U8(algorithm)
+    }
+}
+
+impl From<&'static Algorithm<u16>> for AnyAlgorithm {
+    fn from(algorithm: &'static Algorithm<u16>) -> Self {
+        AnyAlgorithm::U16(algorithm)
+    }
+}
+
+impl From<&'static Algorithm<u32>> for AnyAlgorithm {
+    fn from(algorithm: &'static Algorithm<u32>) -> Self {
+        AnyAlgorithm::U32(algorithm)
+    }
+}
+
+impl From<&'static Algorithm<u64>> for AnyAlgorithm {
+    fn from(algorithm: &'static Algorithm<u64>) -> Self {
+        AnyAlgorithm::U64(algorithm)
+    }
+}
+
+impl From<&'static Algorithm<u128>> for AnyAlgorithm {
+    fn from(algorithm: &'static Algorithm<u128>) -> Self {
+        AnyAlgorithm::U128(algorithm)
+    }
+}
+
+/// A [`Crc`] of any width, whose checksums are widened to `u128`.
+#[derive(Clone)]
+pub enum AnyCrc {
+    U8(Crc<u8, BoxedTable<1>>),
+    U16(Crc<u16, BoxedTable<1>>),
+    U32(Crc<u32, BoxedTable<1>>),
+    U64(Crc<u64, BoxedTable<1>>),
+    U128(Crc<u128, BoxedTable<1>>),
+}
+
+/// A [`Digest`] of an [`AnyCrc`].
+#[derive(Clone)]
+pub enum AnyDigest<'a> {
+    U8(Digest<'a, u8, BoxedTable<1>>),
+    U16(Digest<'a, u16, BoxedTable<1>>),
+    U32(Digest<'a, u32, BoxedTable<1>>),
+    U64(Digest<'a, u64, BoxedTable<1>>),
+    U128(Digest<'a, u128, BoxedTable<1>>),
+}
+
+impl AnyCrc {
+    pub fn new(algorithm: AnyAlgorithm) -> Self {
+        match algorithm {
+            AnyAlgorithm::U8(algorithm) => AnyCrc::U8(Crc::<u8, BoxedTable<1>>::new(algorithm)),
+            AnyAlgorithm::U16(algorithm) => AnyCrc::U16(Crc::<u16, BoxedTable<1>>::new(algorithm)),
+            AnyAlgorithm::U32(algorithm) => AnyCrc::U32(Crc::<u32, BoxedTable<1>>::new(algorithm)),
+            AnyAlgorithm::U64(algorithm) => AnyCrc::U64(Crc::<u64, BoxedTable<1>>::new(algorithm)),
+            AnyAlgorithm::U128(algorithm) => {
+                AnyCrc::U128(Crc::<u128, BoxedTable<1>>::new(algorithm))
+            }
+        }
+    }
+
+    pub fn algorithm(&self) -> AnyAlgorithm {
+        match self {
+            AnyCrc::U8(crc) => crc.algorithm.into(),
+            AnyCrc::U16(crc) => crc.algorithm.into(),
+            AnyCrc::U32(crc) => crc.algorithm.into(),
+            AnyCrc::U64(crc) => crc.algorithm.into(),
+            AnyCrc::U128(crc) => crc.algorithm.into(),
+        }
+    }
+
+    /// Checks that the checksum of `"123456789"` matches the `check` of the algorithm.
+    pub fn verify_check(&self) -> Result<(), AlgorithmError> {
+        match self {
+            AnyCrc::U8(crc) => crc.verify_check(),
+            AnyCrc::U16(crc) => crc.verify_check(),
+            AnyCrc::U32(crc) => crc.verify_check(),
+            AnyCrc::U64(crc) => crc.verify_check(),
+            AnyCrc::U128(crc) => crc.verify_check(),
+        }
+    }
+
+    pub fn checksum(&self, bytes: &[u8]) -> u128 {
+        match self {
+            AnyCrc::U8(crc) => crc.checksum(bytes).into(),
+            AnyCrc::U16(crc) => crc.checksum(bytes).into(),
+            AnyCrc::U32(crc) => crc.checksum(bytes).into(),
+            AnyCrc::U64(crc) => crc.checksum(bytes).into(),
+            AnyCrc::U128(crc) => crc.checksum(bytes),
+        }
+    }
+
+    pub fn digest(&self) -> AnyDigest<'_> {
+        match self {
+            AnyCrc::U8(crc) => AnyDigest::U8(crc.digest()),
+            AnyCrc::U16(crc) => AnyDigest::U16(crc.digest()),
+            AnyCrc::U32(crc) => AnyDigest::U32(crc.digest()),
+            AnyCrc::U64(crc) => AnyDigest::U64(crc.digest()),
+            AnyCrc::U128(crc) => AnyDigest::U128(crc.digest()),
+        }
+    }
+}
+
+impl AnyDigest<'_> {
+    pub fn update(&mut self, bytes: &[u8]) {
+        match self {
+            AnyDigest::U8(digest) => digest.update(bytes),
+            AnyDigest::U16(digest) => digest.update(bytes),
+            AnyDigest::U32(digest) => digest.update(bytes),
+            AnyDigest::U64(digest) => digest.update(bytes),
+            AnyDigest::U128(digest) => digest.update(bytes),
+        }
+    }
+
+    pub fn finalize(self) -> u128 {
+        match self {
+            AnyDigest::U8(digest) => digest.finalize().into(),
+            AnyDigest::U16(digest) => digest.finalize().into(),
+            AnyDigest::U32(digest) => digest.finalize().into(),
+            AnyDigest::U64(digest) => digest.finalize().into(),
+            AnyDigest::U128(digest) => digest.finalize(),
+        }
+    }
+}
+
+/// A runtime registry of named algorithms, to look up [`Crc`]s by name, e.g. from a
+/// configuration file.
+///
+/// [`Registry::new`] contains all algorithms of the catalogue, under their names in the
+/// CRC RevEng catalogue.
+///
+/// ```rust
+/// use crc::{Crc, Registry, Table};
+///
+/// let mut registry = Registry::new();
+/// registry
+///     .register_model("width=16 poly=0x8005 init=0xffff refin=true refout=true xorout=0x0000 check=0x4b37 residue=0x0000 name=\"VENDOR/MODBUS\"")
+///     .unwrap();
+///
+/// let modbus = registry.any_crc("VENDOR/MODBUS").unwrap();
+/// assert_eq!(modbus.checksum(b"123456789"), 0x4b37);
+///
+/// let iscsi: Crc<u32, Table<16>> = registry.crc("CRC-32/ISCSI").unwrap();
+/// assert_eq!(iscsi.checksum(b"123456789"), 0xe3069283);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Registry {
+    algorithms: BTreeMap<String, AnyAlgorithm>,
+}
+
+impl Registry {
+    /// Creates a registry of all algorithms of the catalogue.
+    pub fn new() -> Self {
+        Self {
+            algorithms: CATALOG
+                .iter()
+                .map(|&(name, algorithm)| (name.to_owned(), algorithm))
+                .collect(),
+        }
+    }
+
+    /// Creates a registry without any algorithms.
+    pub fn empty() -> Self {
+        Self {
+            algorithms: BTreeMap::new(),
+        }
+    }
+
+    /// Registers `algorithm` under `name`, returning the algorithm previously registered
+    /// under that name.
+    ///
+    /// Returns an error without registering anything if `algorithm` is not valid, see
+    /// [`validate`], or if its checksum of `"123456789"` does not match its `check`.
+    ///
+    /// [`Crc`] holds a `'static` reference to its algorithm, so algorithms that are not
+    /// in the catalogue are leaked. Each distinct algorithm is leaked only once, so
+    /// registering the same algorithms again, e.g. when reloading a configuration, does
+    /// not allocate.
+    pub fn register<W: Register>(
+        &mut self,
+        name: impl Into<String>,
+        algorithm: Algorithm<W>,
+    ) -> Result<Option<AnyAlgorithm>, AlgorithmError>
+    where
+        AnyAlgorithm: From<&'static Algorithm<W>>,
+    {
+        W::validate(&algorithm)?;
+        if W::checksum(&algorithm, b"123456789") != algorithm.check {
+            return Err(AlgorithmError::CheckMismatch);
+        }
+        Ok(self.algorithms.insert(name.into(), intern(algorithm)))
+    }
+
+    /// Parses a model string with a name, see [`Model::parse`], and registers its
+    /// algorithm under that name with the smallest fitting register type, see
+    /// [`register`](Self::register).
+    pub fn register_model(&mut self, src: &str) -> Result<AnyAlgorithm, ParseModelError> {
+        let model = Model::<u128>::parse(src)?;
+        let name = model.name.ok_or(ParseModelError::MissingField("name"))?;
+        let algorithm = model.algorithm;
+        match algorithm.width {
+            1..=8 => self.register(name, narrow::<u8>(&algorithm)),
+            9..=16 => self.register(name, narrow::<u16>(&algorithm)),
+            17..=32 => self.register(name, narrow::<u32>(&algorithm)),
+            33..=64 => self.register(name, narrow::<u64>(&algorithm)),
+            _ => self.register(name, algorithm),
+        }
+        .map_err(ParseModelError::Algorithm)?;
+        Ok(self.algorithms[name])
+    }
+
+    /// Returns the algorithm registered under `name`.
+    pub fn get(&self, name: &str) -> Option<AnyAlgorithm> {
+        self.algorithms.get(name).copied()
+    }
+
+    /// Returns the algorithm registered under `name` if its register type is `W`.
+    pub fn algorithm<W: Width + 'static>(&self, name: &str) -> Option<&'static Algorithm<W>> {
+        self.get(name)?.downcast()
+    }
+
+    /// Returns a [`Crc`] for the algorithm registered under `name` if its register type
+    /// is `W`.
+    ///
+    /// The data of `I` is built on the stack before it is moved into the `Crc`, e.g.
+    /// 64 KiB for `Crc<u128, Table<16>>`, so prefer [`BoxedTable<L>`] for large tables.
+    pub fn crc<W: Width + 'static, I: Build<W>>(&self, name: &str) -> Option<Crc<W, I>> {
+        let algorithm = self.algorithm(name)?;
+        Some(Crc {
+            algorithm,
+            data: I::build(algorithm),
+        })
+    }
+
+    /// Returns an [`AnyCrc`] for the algorithm registered under `name`.
+    pub fn any_crc(&self, name: &str) -> Option<AnyCrc> {
+        self.get(name).map(AnyCrc::new)
+    }
+
+    /// Returns the registered names in lexicographic order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.algorithms.keys().map(String::as_str)
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Algorithms leaked by [`intern`].
+static INTERNED: Mutex<Vec<AnyAlgorithm>> = Mutex::new(Vec::new());
+
+/// Returns the algorithm of the catalogue or a previously leaked algorithm equal to
+/// `algorithm`, and only leaks `algorithm` if there is none.
+fn intern<W: Register>(algorithm: Algorithm<W>) -> AnyAlgorithm
+where
+    AnyAlgorithm: From<&'static Algorithm<W>>,
+{
+    let mut interned = INTERNED.lock().unwrap_or_else(PoisonError::into_inner);
+    let existing = CATALOG
+        .iter()
+        .map(|(_, algorithm)| algorithm)
+        .chain(interned.iter())
+        .filter_map(AnyAlgorithm::downcast::<W>)
+        .find(|&existing| *existing == algorithm);
+    match existing {
+        Some(existing) => existing.into(),
+        None => {
+            let algorithm: &'static Algorithm<W> = Box::leak(Box::new(algorithm));
+            interned.push(algorithm.into());
+            algorithm.into()
+        }
+    }
+}
+
+/// Converts a valid algorithm to a register type that fits its width.
+fn narrow<W: Width + TryFrom<u128>>(algorithm: &Algorithm<u128>) -> Algorithm<W> {
+    let narrow = |value: u128| W::try_from(value).ok().expect("value fits width");
+    Algorithm {
+        width: algorithm.width,
+        poly: narrow(algorithm.poly),
+        init: narrow(algorithm.init),
+        refin: algorithm.refin,
+        refout: algorithm.refout,
+        xorout: narrow(algorithm.xorout),
+        check: narrow(algorithm.check),
+        residue: narrow(algorithm.residue),
+    }
+}
@@ -5,6 +5,13 @@ pub(crate) const fn crc8_table<const L: usize>(
     poly: u8,
     reflect: bool,
 ) -> [[u8; 256]; L] {
+    let mut table = [[0u8; 256]; L];
+    fill_crc8_table(&mut table, width, poly, reflect);
+    table
+}
+
+/// Fills all lanes of `table` in place, e.g. for tables allocated on the heap.
+pub(crate) const fn fill_crc8_table(table: &mut [[u8; 256]], width: u8, poly: u8, reflect: bool) {
     let poly = if reflect {
         let poly = poly.reverse_bits();
         poly >> (8u8 - width)
@@ -12,21 +19,21 @@ pub(crate) const fn crc8_table<const L: usize>(
         poly << (8u8 - width)
     };
 
-    let mut table = [[0u8; 256]; L];
+    let lanes = table.len();
 
     // Generate first table (lane 0) directly
     let mut i = 0;
-    while i < 256 && L > 0 {
+    while i < 256 && lanes > 0 {
         table[0][i] = crc8(poly, reflect, i as u8);
         i += 1;
     }
 
     // Generate subsequent lanes based on lane 0
-    if L > 1 {
+    if lanes > 1 {
         let mut i = 0;
         while i < 256 {
             let mut e = 1;
-            while e < L {
+            while e < lanes {
                 let one_lower = table[e - 1][i];
                 table[e][i] = table[0][one_lower as usize];
                 e += 1;
@@ -34,7 +41,28 @@ pub(crate) const fn crc8_table<const L: usize>(
             i += 1;
         }
     }
+}
+
+/// Lookup table processing 4 bits at a time.
+pub(crate) const fn crc8_nibble_table(width: u8, poly: u8, reflect: bool) -> [u8; 16] {
+    let poly = if reflect {
+        let poly = poly.reverse_bits();
+        poly >> (8u8 - width)
+    } else {
+        poly << (8u8 - width)
+    };
 
+    // The first 4 of the 8 steps in `crc8` only shift the value into place
+    let mut table = [0u8; 16];
+    let mut i = 0;
+    while i < 16 {
+        table[i] = if reflect {
+            crc8(poly, reflect, (i as u8) << 4)
+        } else {
+            crc8(poly, reflect, i as u8)
+        };
+        i += 1;
+    }
     table
 }
 
@@ -43,6 +71,18 @@ pub(crate) const fn crc16_table<const L: usize>(
     poly: u16,
     reflect: bool,
 ) -> [[u16; 256]; L] {
+    let mut table = [[0u16; 256]; L];
+    fill_crc16_table(&mut table, width, poly, reflect);
+    table
+}
+
+/// Fills all lanes of `table` in place, e.g. for tables allocated on the heap.
+pub(crate) const fn fill_crc16_table(
+    table: &mut [[u16; 256]],
+    width: u8,
+    poly: u16,
+    reflect: bool,
+) {
     let poly = if reflect {
         let poly = poly.reverse_bits();
         poly >> (16u8 - width)
@@ -50,21 +90,21 @@ pub(crate) const fn crc16_table<const L: usize>(
         poly << (16u8 - width)
     };
 
-    let mut table = [[0u16; 256]; L];
+    let lanes = table.len();
 
     // Generate first table (lane 0) directly
     let mut i = 0;
-    while i < 256 && L > 0 {
+    while i < 256 && lanes > 0 {
         table[0][i] = crc16(poly, reflect, i as u16);
         i += 1;
     }
 
     // Generate subsequent lanes based on lane 0
-    if L > 1 {
+    if lanes > 1 {
         let mut i = 0;
         while i < 256 {
             let mut e = 1;
-            while e < L {
+            while e < lanes {
                 let one_lower = table[e - 1][i];
                 if reflect {
                     table[e][i] = (one_lower >> 8) ^ table[0][(one_lower & 0xFF) as usize];
@@ -76,7 +116,28 @@ pub(crate) const fn crc16_table<const L: usize>(
             i += 1;
         }
     }
+}
 
+/// Lookup table processing 4 bits at a time.
+pub(crate) const fn crc16_nibble_table(width: u8, poly: u16, reflect: bool) -> [u16; 16] {
+    let poly = if reflect {
+        let poly = poly.reverse_bits();
+        poly >> (16u8 - width)
+    } else {
+        poly << (16u8 - width)
+    };
+
+    // The first 4 of the 8 steps in `crc16` only shift the value into place
+    let mut table = [0u16; 16];
+    let mut i = 0;
+    while i < 16 {
+        table[i] = if reflect {
+            crc16(poly, reflect, (i as u16) << 4)
+        } else {
+            crc16(poly, reflect, i as u16)
+        };
+        i += 1;
+    }
     table
 }
 
@@ -85,6 +146,18 @@ pub(crate) const fn crc32_table<const L: usize>(
     poly: u32,
     reflect: bool,
 ) -> [[u32; 256]; L] {
+    let mut table = [[0u32; 256]; L];
+    fill_crc32_table(&mut table, width, poly, reflect);
+    table
+}
+
+/// Fills all lanes of `table` in place, e.g. for tables allocated on the heap.
+pub(crate) const fn fill_crc32_table(
+    table: &mut [[u32; 256]],
+    width: u8,
+    poly: u32,
+    reflect: bool,
+) {
     let poly = if reflect {
         let poly = poly.reverse_bits();
         poly >> (32u8 - width)
@@ -92,21 +165,21 @@ pub(crate) const fn crc32_table<const L: usize>(
         poly << (32u8 - width)
     };
 
-    let mut table = [[0u32; 256]; L];
+    let lanes = table.len();
 
     // Generate first table (lane 0) directly
     let mut i = 0;
-    while i < 256 && L > 0 {
+    while i < 256 && lanes > 0 {
         table[0][i] = crc32(poly, reflect, i as u32);
         i += 1;
     }
 
     // Generate subsequent lanes based on lane 0
-    if L > 1 {
+    if lanes > 1 {
         let mut i = 0;
         while i < 256 {
             let mut e = 1;
-            while e < L {
+            while e < lanes {
                 let one_lower = table[e - 1][i];
                 if reflect {
                     table[e][i] = (one_lower >> 8) ^ table[0][(one_lower & 0xFF) as usize];
@@ -118,7 +191,64 @@ pub(crate) const fn crc32_table<const L: usize>(
             i += 1;
         }
     }
+}
+
+/// Lookup table processing 4 bits at a time.
+pub(crate) const fn crc32_nibble_table(width: u8, poly: u32, reflect: bool) -> [u32; 16] {
+    let poly = if reflect {
+        let poly = poly.reverse_bits();
+        poly >> (32u8 - width)
+    } else {
+        poly << (32u8 - width)
+    };
 
+    // The first 4 of the 8 steps in `crc32` only shift the value into place
+    let mut table = [0u32; 16];
+    let mut i = 0;
+    while i < 16 {
+        table[i] = if reflect {
+            crc32(poly, reflect, (i as u32) << 4)
+        } else {
+            crc32(poly, reflect, i as u32)
+        };
+        i += 1;
+    }
+    table
+}
+
+/// Lookup tables for `N` braids of 64-bit words: the bytewise table, followed by one
+/// table per byte of a word, which advances the CRC of that byte to the same position
+/// in the next word of its braid.
+pub(crate) const fn crc32_braid_table<const N: usize>(
+    width: u8,
+    poly: u32,
+    reflect: bool,
+) -> [[u32; 256]; 9] {
+    const { assert!(N > 0, "at least one braid is required") };
+    let mut table = [[0u32; 256]; 9];
+    table[0] = crc32_table::<1>(width, poly, reflect)[0];
+
+    let mut i = 0;
+    while i < 256 {
+        // The last byte of a word is followed by the words of the other braids
+        let mut crc = table[0][i];
+        let mut lane = 8;
+        let mut n = 8 * (N - 1);
+        while lane > 0 {
+            while n > 0 {
+                crc = if reflect {
+                    (crc >> 8) ^ table[0][(crc & 0xFF) as usize]
+                } else {
+                    (crc << 8) ^ table[0][(crc >> 24) as usize]
+                };
+                n -= 1;
+            }
+            table[lane][i] = crc;
+            lane -= 1;
+            n = 1;
+        }
+        i += 1;
+    }
     table
 }
 
@@ -127,6 +257,18 @@ pub(crate) const fn crc64_table<const L: usize>(
     poly: u64,
     reflect: bool,
 ) -> [[u64; 256]; L] {
+    let mut table = [[0u64; 256]; L];
+    fill_crc64_table(&mut table, width, poly, reflect);
+    table
+}
+
+/// Fills all lanes of `table` in place, e.g. for tables allocated on the heap.
+pub(crate) const fn fill_crc64_table(
+    table: &mut [[u64; 256]],
+    width: u8,
+    poly: u64,
+    reflect: bool,
+) {
     let poly = if reflect {
         let poly = poly.reverse_bits();
         poly >> (64u8 - width)
@@ -134,21 +276,21 @@ pub(crate) const fn crc64_table<const L: usize>(
         poly << (64u8 - width)
     };
 
-    let mut table = [[0u64; 256]; L];
+    let lanes = table.len();
 
     // Generate first table (lane 0) directly
     let mut i = 0;
-    while i < 256 && L > 0 {
+    while i < 256 && lanes > 0 {
         table[0][i] = crc64(poly, reflect, i as u64);
         i += 1;
     }
 
     // Generate subsequent lanes based on lane 0
-    if L > 1 {
+    if lanes > 1 {
         let mut i = 0;
         while i < 256 {
             let mut e = 1;
-            while e < L {
+            while e < lanes {
                 let one_lower = table[e - 1][i];
                 if reflect {
                     table[e][i] = (one_lower >> 8) ^ table[0][(one_lower & 0xFF) as usize];
@@ -160,7 +302,64 @@ pub(crate) const fn crc64_table<const L: usize>(
             i += 1;
         }
     }
+}
+
+/// Lookup table processing 4 bits at a time.
+pub(crate) const fn crc64_nibble_table(width: u8, poly: u64, reflect: bool) -> [u64; 16] {
+    let poly = if reflect {
+        let poly = poly.reverse_bits();
+        poly >> (64u8 - width)
+    } else {
+        poly << (64u8 - width)
+    };
+
+    // The first 4 of the 8 steps in `crc64` only shift the value into place
+    let mut table = [0u64; 16];
+    let mut i = 0;
+    while i < 16 {
+        table[i] = if reflect {
+            crc64(poly, reflect, (i as u64) << 4)
+        } else {
+            crc64(poly, reflect, i as u64)
+        };
+        i += 1;
+    }
+    table
+}
+
+/// Lookup tables for `N` braids of 64-bit words: the bytewise table, followed by one
+/// table per byte of a word, which advances the CRC of that byte to the same position
+/// in the next word of its braid.
+pub(crate) const fn crc64_braid_table<const N: usize>(
+    width: u8,
+    poly: u64,
+    reflect: bool,
+) -> [[u64; 256]; 9] {
+    const { assert!(N > 0, "at least one braid is required") };
+    let mut table = [[0u64; 256]; 9];
+    table[0] = crc64_table::<1>(width, poly, reflect)[0];
 
+    let mut i = 0;
+    while i < 256 {
+        // The last byte of a word is followed by the words of the other braids
+        let mut crc = table[0][i];
+        let mut lane = 8;
+        let mut n = 8 * (N - 1);
+        while lane > 0 {
+            while n > 0 {
+                crc = if reflect {
+                    (crc >> 8) ^ table[0][(crc & 0xFF) as usize]
+                } else {
+                    (crc << 8) ^ table[0][(crc >> 56) as usize]
+                };
+                n -= 1;
+            }
+            table[lane][i] = crc;
+            lane -= 1;
+            n = 1;
+        }
+        i += 1;
+    }
     table
 }
 
@@ -169,6 +368,18 @@ pub(crate) const fn crc128_table<const L: usize>(
     poly: u128,
     reflect: bool,
 ) -> [[u128; 256]; L] {
+    let mut table = [[0u128; 256]; L];
+    fill_crc128_table(&mut table, width, poly, reflect);
+    table
+}
+
+/// Fills all lanes of `table` in place, e.g. for tables allocated on the heap.
+pub(crate) const fn fill_crc128_table(
+    table: &mut [[u128; 256]],
+    width: u8,
+    poly: u128,
+    reflect: bool,
+) {
     let poly = if reflect {
         let poly = poly.reverse_bits();
         poly >> (128u8 - width)
@@ -176,21 +387,21 @@ pub(crate) const fn crc128_table<const L: usize>(
         poly << (128u8 - width)
     };
 
-    let mut table = [[0u128; 256]; L];
+    let lanes = table.len();
 
     // Generate first table (lane 0) directly
     let mut i = 0;
-    while i < 256 && L > 0 {
+    while i < 256 && lanes > 0 {
         table[0][i] = crc128(poly, reflect, i as u128);
         i += 1;
     }
 
     // Generate subsequent lanes based on lane 0
-    if L > 1 {
+    if lanes > 1 {
         let mut i = 0;
         while i < 256 {
             let mut e = 1;
-            while e < L {
+            while e < lanes {
                 let one_lower = table[e - 1][i];
                 if reflect {
                     table[e][i] = (one_lower >> 8) ^ table[0][(one_lower & 0xFF) as usize];
@@ -202,6 +413,39 @@ pub(crate) const fn crc128_table<const L: usize>(
             i += 1;
         }
     }
+}
 
+/// Lookup table processing 4 bits at a time.
+pub(crate) const fn crc128_nibble_table(width: u8, poly: u128, reflect: bool) -> [u128; 16] {
+    let poly = if reflect {
+        let poly = poly.reverse_bits();
+        poly >> (128u8 - width)
+    } else {
+        poly << (128u8 - width)
+    };
+
+    // The first 4 of the 8 steps in `crc128` only shift the value into place
+    let mut table = [0u128; 16];
+    let mut i = 0;
+    while i < 16 {
+        table[i] = if reflect {
+            crc128(poly, reflect, (i as u128) << 4)
+        } else {
+            crc128(poly, reflect, i as u128)
+        };
+        i += 1;
+    }
     table
 }
+
+/// Allocates a zeroed table with `L` lanes directly on the heap.
+#[cfg(feature = "alloc")]
+pub(crate) fn boxed_table<W: Copy + Default, const L: usize>() -> alloc::boxed::Box<[[W; 256]; L]> {
+    match alloc::vec![[W::default(); 256]; L]
+        .into_boxed_slice()
+        .try_into()
+    {
+        Ok(table) => table,
+        Err(_) => unreachable!(),
+    }
+}
@@ -1,3 +1,5 @@
+use crate::AlgorithmError;
+
 pub(crate) const fn crc8(poly: u8, reflect: bool, mut value: u8) -> u8 {
     let mut i = 0;
     if reflect {
@@ -89,3 +91,276 @@ pub(crate) const fn crc128(poly: u128, reflect: bool, mut value: u128) -> u128 {
     }
     value
 }
+
+/// Multiplies the polynomial `a` by `x` modulo the generator `poly` of degree `width`.
+///
+/// Polynomials are right-aligned in normal (MSB-first) form without the leading term.
+pub(crate) const fn mulx(a: u128, poly: u128, width: u8) -> u128 {
+    let top = (a >> (width - 1)) & 1;
+    ((a << 1) ^ (top * poly)) & mask(width)
+}
+
+/// Multiplies the polynomials `a` and `b` modulo the generator `poly` of degree `width`.
+pub(crate) const fn mulmod(mut a: u128, mut b: u128, poly: u128, width: u8) -> u128 {
+    let mut product = 0;
+    while b != 0 {
+        product ^= (b & 1) * a;
+        a = mulx(a, poly, width);
+        b >>= 1;
+    }
+    product
+}
+
+/// Computes `x^(8 * n)` modulo the generator `poly` of degree `width`, i.e. the
+/// factor that shifts a CRC register through `n` zero bytes.
+pub(crate) const fn xpow8n(n: usize, poly: u128, width: u8) -> u128 {
+    let mut power = 1;
+    let mut i = 0;
+    while i < 8 {
+        power = mulx(power, poly, width);
+        i += 1;
+    }
+    powmod(power, n as u128, poly, width)
+}
+
+/// Computes `a^n` modulo the generator `poly` of degree `width`.
+pub(crate) const fn powmod(mut a: u128, mut n: u128, poly: u128, width: u8) -> u128 {
+    let mut result = reduce(1, poly, width);
+    while n != 0 {
+        if n & 1 == 1 {
+            result = mulmod(result, a, poly, width);
+        }
+        a = mulmod(a, a, poly, width);
+        n >>= 1;
+    }
+    result
+}
+
+/// Reduces the polynomial `a` of any degree below 128 modulo the generator `poly` of
+/// degree `width`.
+pub(crate) const fn reduce(mut a: u128, poly: u128, width: u8) -> u128 {
+    let mut i = 128;
+    while i > width as u32 {
+        i -= 1;
+        if (a >> i) & 1 == 1 {
+            a ^= (1 << i) ^ (poly << (i - width as u32));
+        }
+    }
+    a
+}
+
+/// Full carry-less multiplication of `a` and `b`, returning the low and high halves.
+pub(crate) const fn clmul128(a: u128, b: u128) -> (u128, u128) {
+    let mut lo = 0;
+    let mut hi = 0;
+    let mut i = 0;
+    while i < 128 {
+        if (b >> i) & 1 == 1 {
+            lo ^= a << i;
+            if i > 0 {
+                hi ^= a >> (128 - i);
+            }
+        }
+        i += 1;
+    }
+    (lo, hi)
+}
+
+/// Divides the polynomial `a` by the nonzero polynomial `b`, both with explicit leading
+/// terms, returning the quotient and the remainder.
+pub(crate) const fn divrem(mut a: u128, b: u128) -> (u128, u128) {
+    let degree = 127 - b.leading_zeros();
+    let mut quotient = 0;
+    while a != 0 && 127 - a.leading_zeros() >= degree {
+        let shift = 127 - a.leading_zeros() - degree;
+        quotient |= 1 << shift;
+        a ^= b << shift;
+    }
+    (quotient, a)
+}
+
+/// Greatest common divisor of the integers `a` and `b`.
+pub(crate) const fn gcd_int(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let rem = a % b;
+        a = b;
+        b = rem;
+    }
+    a
+}
+
+/// Greatest common divisor of the polynomials `a` and `b`.
+pub(crate) const fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let rem = divrem(a, b).1;
+        a = b;
+        b = rem;
+    }
+    a
+}
+
+/// Carry-less multiplication of `a` and `b`, truncated to the low 64 bits.
+///
+/// Each operand is split into four parts with three zero bits between any two set bits,
+/// so that the carries of the integer products never reach a bit that is kept.
+pub(crate) const fn clmul_lo(a: u64, b: u64) -> u64 {
+    const MASKS: [u64; 4] = [
+        0x1111111111111111,
+        0x2222222222222222,
+        0x4444444444444444,
+        0x8888888888888888,
+    ];
+    let mut product = 0;
+    let mut i = 0;
+    while i < 4 {
+        let mut part = 0;
+        let mut j = 0;
+        while j < 4 {
+            part ^= (a & MASKS[j]).wrapping_mul(b & MASKS[(i + 4 - j) % 4]);
+            j += 1;
+        }
+        product |= part & MASKS[i];
+        i += 1;
+    }
+    product
+}
+
+/// Carry-less multiplication of `a` and `b`, high 64 bits.
+pub(crate) const fn clmul_hi(a: u64, b: u64) -> u64 {
+    // The product of the reversed operands is the reversed product, shifted by one
+    (clmul_lo(a.reverse_bits(), b.reverse_bits()) << 1).reverse_bits()
+}
+
+/// Constants for Barrett reduction modulo the generator `poly` of degree `width` (at
+/// most 64): the low 64 bits of x^(64+width) / P, reversed for reflected algorithms, and
+/// the generator itself.
+pub(crate) const fn barrett_constants(width: u8, poly: u64, reflect: bool) -> [u64; 2] {
+    // The quotient has degree 64, its bits are the carries of repeated multiplication by x
+    let mut quotient = 0;
+    let mut rem = poly as u128;
+    let mut i = 64;
+    while i > 0 {
+        i -= 1;
+        quotient |= (((rem >> (width - 1)) & 1) as u64) << i;
+        rem = mulx(rem, poly as u128, width);
+    }
+
+    if reflect {
+        quotient = quotient.reverse_bits();
+    }
+    [quotient, poly]
+}
+
+/// Updates the right-aligned register `crc` (reflected for reflected algorithms) using
+/// the constants from `barrett_constants`, 8 bytes at a time.
+pub(crate) const fn update_barrett(
+    mut crc: u64,
+    width: u8,
+    reflect: bool,
+    constants: &[u64; 2],
+    bytes: &[u8],
+) -> u64 {
+    let [quotient, poly] = *constants;
+    let mut i = 0;
+
+    // The register is folded into the next 8 bytes, which are then multiplied by x^width
+    // and reduced: the Barrett quotient of the product comes from one multiplication,
+    // and the remainder is the low bits of the quotient times the generator.
+    if reflect {
+        while i + 8 <= bytes.len() {
+            let word = *bytes.split_at(i).1.first_chunk::<8>().unwrap();
+            let value = u64::from_le_bytes(word) ^ crc;
+            let q = value ^ (clmul_lo(value, quotient) << 1);
+            crc = clmul_lo(q.reverse_bits(), poly).reverse_bits() >> (64 - width);
+            i += 8;
+        }
+    } else {
+        while i + 8 <= bytes.len() {
+            let word = *bytes.split_at(i).1.first_chunk::<8>().unwrap();
+            let value = u64::from_be_bytes(word) ^ (crc << (64 - width));
+            let q = value ^ clmul_hi(value, quotient);
+            crc = clmul_lo(q, poly) & (mask(width) as u64);
+            i += 8;
+        }
+    }
+
+    // Process remaining bytes one bit at a time
+    if reflect {
+        let poly = poly.reverse_bits() >> (64 - width);
+        while i < bytes.len() {
+            crc ^= bytes[i] as u64;
+            let mut bit = 0;
+            while bit < 8 {
+                crc = (crc >> 1) ^ ((crc & 1) * poly);
+                bit += 1;
+            }
+            i += 1;
+        }
+    } else {
+        let poly = poly << (64 - width);
+        let mut value = crc << (64 - width);
+        while i < bytes.len() {
+            value ^= (bytes[i] as u64) << 56;
+            let mut bit = 0;
+            while bit < 8 {
+                value = (value << 1) ^ ((value >> 63) * poly);
+                bit += 1;
+            }
+            i += 1;
+        }
+        crc = value >> (64 - width);
+    }
+    crc
+}
+
+/// Checks that `width` fits into a register of `bits` bits, and that `poly`, `init`,
+/// `xorout`, `check` and `residue` (in that order) fit into `width` bits.
+pub(crate) const fn validate(
+    bits: u32,
+    width: u8,
+    values: [u128; 5],
+) -> Result<(), AlgorithmError> {
+    const ERRORS: [AlgorithmError; 5] = [
+        AlgorithmError::Poly,
+        AlgorithmError::Init,
+        AlgorithmError::Xorout,
+        AlgorithmError::Check,
+        AlgorithmError::Residue,
+    ];
+    if width == 0 || width as u32 > bits {
+        return Err(AlgorithmError::Width);
+    }
+    let mut i = 0;
+    while i < values.len() {
+        if values[i] & !mask(width) != 0 {
+            return Err(ERRORS[i]);
+        }
+        i += 1;
+    }
+    Ok(())
+}
+
+/// Computes the residue of an algorithm: `xorout`, reflected if `refout`, shifted through
+/// `width` zero bits, and reflected if `refin`.
+pub(crate) const fn residue(
+    width: u8,
+    poly: u128,
+    xorout: u128,
+    refin: bool,
+    refout: bool,
+) -> u128 {
+    let mut value = xorout;
+    if refout {
+        value = value.reverse_bits() >> (128 - width as u32);
+    }
+    let power = powmod(mulx(1, poly, width), width as u128, poly, width);
+    value = mulmod(value, power, poly, width);
+    if refin {
+        value = value.reverse_bits() >> (128 - width as u32);
+    }
+    value
+}
+
+const fn mask(width: u8) -> u128 {
+    u128::MAX >> (128 - width as u32)
+}
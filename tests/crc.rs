@@ -46,6 +46,16 @@ fn crc_8() {
     for alg in algs.iter() {
         let crc = Crc::<u8>::new(alg);
         assert_eq!(alg.check, crc.checksum(INIT));
+        assert_eq!(validate(alg), Ok(()));
+        let built = AlgorithmBuilder::<u8>::new(alg.width, alg.poly)
+            .init(alg.init)
+            .refin(alg.refin)
+            .refout(alg.refout)
+            .xorout(alg.xorout)
+            .build();
+        assert_eq!(&built, alg);
+        assert_eq!(crc.verify_check(), Ok(()));
+        assert_eq!(alg.check, Crc::<u8, Barrett>::new(alg).checksum(INIT));
         let mut digest = crc.digest();
         digest.update(INIT_PART1);
         digest.update(INIT_PART2);
@@ -104,6 +114,16 @@ fn crc_16() {
     for alg in algs.iter() {
         let crc = Crc::<u16>::new(alg);
         assert_eq!(alg.check, crc.checksum(INIT));
+        assert_eq!(validate(alg), Ok(()));
+        let built = AlgorithmBuilder::<u16>::new(alg.width, alg.poly)
+            .init(alg.init)
+            .refin(alg.refin)
+            .refout(alg.refout)
+            .xorout(alg.xorout)
+            .build();
+        assert_eq!(&built, alg);
+        assert_eq!(crc.verify_check(), Ok(()));
+        assert_eq!(alg.check, Crc::<u16, Barrett>::new(alg).checksum(INIT));
         let mut digest = crc.digest();
         digest.update(INIT_PART1);
         digest.update(INIT_PART2);
@@ -141,6 +161,16 @@ fn crc_32() {
     for alg in algs {
         let crc = Crc::<u32>::new(alg);
         assert_eq!(alg.check, crc.checksum(INIT));
+        assert_eq!(validate(alg), Ok(()));
+        let built = AlgorithmBuilder::<u32>::new(alg.width, alg.poly)
+            .init(alg.init)
+            .refin(alg.refin)
+            .refout(alg.refout)
+            .xorout(alg.xorout)
+            .build();
+        assert_eq!(&built, alg);
+        assert_eq!(crc.verify_check(), Ok(()));
+        assert_eq!(alg.check, Crc::<u32, Barrett>::new(alg).checksum(INIT));
         let mut digest = crc.digest();
         digest.update(INIT_PART1);
         digest.update(INIT_PART2);
@@ -160,6 +190,16 @@ fn crc_64() {
     for alg in algs {
         let crc = Crc::<u64>::new(alg);
         assert_eq!(alg.check, crc.checksum(INIT));
+        assert_eq!(validate(alg), Ok(()));
+        let built = AlgorithmBuilder::<u64>::new(alg.width, alg.poly)
+            .init(alg.init)
+            .refin(alg.refin)
+            .refout(alg.refout)
+            .xorout(alg.xorout)
+            .build();
+        assert_eq!(&built, alg);
+        assert_eq!(crc.verify_check(), Ok(()));
+        assert_eq!(alg.check, Crc::<u64, Barrett>::new(alg).checksum(INIT));
         let mut digest = crc.digest();
         digest.update(INIT_PART1);
         digest.update(INIT_PART2);
@@ -173,9 +213,649 @@ fn crc_128() {
     for alg in algs {
         let crc = Crc::<u128>::new(alg);
         assert_eq!(alg.check, crc.checksum(INIT));
+        assert_eq!(validate(alg), Ok(()));
+        let built = AlgorithmBuilder::<u128>::new(alg.width, alg.poly)
+            .init(alg.init)
+            .refin(alg.refin)
+            .refout(alg.refout)
+            .xorout(alg.xorout)
+            .build();
+        assert_eq!(&built, alg);
+        assert_eq!(crc.verify_check(), Ok(()));
         let mut digest = crc.digest();
         digest.update(INIT_PART1);
         digest.update(INIT_PART2);
         assert_eq!(alg.check, digest.finalize());
     }
 }
+
+#[test]
+fn try_new() {
+    const INVALID: Result<Crc<u32>, AlgorithmError> = Crc::<u32>::try_new(&Algorithm {
+        width: 0,
+        ..CRC_32_ISCSI
+    });
+    assert!(matches!(INVALID, Err(AlgorithmError::Width)));
+
+    let cases: [(Algorithm<u16>, AlgorithmError); 6] = [
+        (
+            Algorithm {
+                width: 17,
+                ..CRC_16_ARC
+            },
+            AlgorithmError::Width,
+        ),
+        (
+            Algorithm {
+                width: 15,
+                ..CRC_16_ARC
+            },
+            AlgorithmError::Poly,
+        ),
+        (
+            Algorithm {
+                init: 0x8000,
+                ..CRC_15_CAN
+            },
+            AlgorithmError::Init,
+        ),
+        (
+            Algorithm {
+                xorout: 0xffff,
+                ..CRC_15_CAN
+            },
+            AlgorithmError::Xorout,
+        ),
+        (
+            Algorithm {
+                check: 0x8000,
+                ..CRC_15_CAN
+            },
+            AlgorithmError::Check,
+        ),
+        (
+            Algorithm {
+                residue: 0xffff,
+                ..CRC_15_CAN
+            },
+            AlgorithmError::Residue,
+        ),
+    ];
+    for (alg, err) in cases {
+        let alg: &'static Algorithm<u16> = Box::leak(Box::new(alg));
+        assert_eq!(validate(alg), Err(err));
+        assert!(matches!(Crc::<u16>::try_new(alg), Err(e) if e == err));
+        #[cfg(feature = "alloc")]
+        assert!(matches!(Crc::<u16, BoxedTable<1>>::try_new(alg), Err(e) if e == err));
+        assert!(matches!(Crc::<u16, NibbleTable>::try_new(alg), Err(e) if e == err));
+        assert!(matches!(Crc::<u16, Barrett>::try_new(alg), Err(e) if e == err));
+        assert!(matches!(CrcTable::<u16, 1>::try_new(alg), Err(e) if e == err));
+    }
+
+    const WIDE: Algorithm<u32> = Algorithm {
+        width: 33,
+        ..CRC_32_ISCSI
+    };
+    assert!(matches!(
+        Crc::<u32, Braid<5>>::try_new(&WIDE),
+        Err(AlgorithmError::Width)
+    ));
+    assert!(matches!(
+        Crc::<u32, Chorba>::try_new(&WIDE),
+        Err(AlgorithmError::Width)
+    ));
+    assert!(matches!(
+        Crc::<u32, Chorba>::try_new(&CRC_32_AIXM),
+        Err(AlgorithmError::Unsupported)
+    ));
+    assert!(Crc::<u32, Chorba>::try_new(&CRC_32_ISCSI).is_ok());
+    assert!(Crc::<u64, Braid<5>>::try_new(&CRC_64_XZ).is_ok());
+    assert!(Crc::<u128, NibbleTable>::try_new(&CRC_82_DARC).is_ok());
+
+    static TABLE: CrcTable<u32, 16> = CrcTable::<u32, 16>::new(&CRC_32_ISO_HDLC);
+    assert!(Crc::<u32, SharedTable<16>>::try_with_table(&CRC_32_JAMCRC, &TABLE).is_ok());
+    assert!(matches!(
+        Crc::<u32, SharedTable<16>>::try_with_table(&CRC_32_ISCSI, &TABLE),
+        Err(AlgorithmError::TableMismatch)
+    ));
+    assert!(matches!(
+        Crc::<u32, SharedTable<16>>::try_with_table(&WIDE, &TABLE),
+        Err(AlgorithmError::Width)
+    ));
+
+    const WRONG_CHECK: Algorithm<u32> = Algorithm {
+        check: 0,
+        ..CRC_32_ISCSI
+    };
+    let crc = Crc::<u32, Table<16>>::try_new(&WRONG_CHECK).unwrap();
+    assert_eq!(crc.verify_check(), Err(AlgorithmError::CheckMismatch));
+    let crc = Crc::<u32, Braid<5>>::new(&WRONG_CHECK);
+    assert_eq!(crc.verify_check(), Err(AlgorithmError::CheckMismatch));
+    assert_eq!(
+        Crc::<u32, Braid<5>>::new(&CRC_32_ISCSI).verify_check(),
+        Ok(())
+    );
+}
+
+#[test]
+#[should_panic(expected = "invalid algorithm")]
+fn builder_invalid() {
+    let _ = AlgorithmBuilder::<u8>::new(9, 0x07).build();
+}
+
+#[test]
+fn model() {
+    const GSM: &str = "width=3 poly=0x3 init=0x0 refin=false refout=false xorout=0x7 check=0x4 residue=0x2 name=\"CRC-3/GSM\"";
+    const DARC: &str = "width=82 poly=0x0308c0111011401440411 init=0x000000000000000000000 refin=true refout=true xorout=0x000000000000000000000 check=0x09ea83f625023801fd612 residue=0x000000000000000000000 name=\"CRC-82/DARC\"";
+
+    let model = Model::<u8>::parse(GSM).unwrap();
+    assert_eq!(model.algorithm, CRC_3_GSM);
+    assert_eq!(model.name, Some("CRC-3/GSM"));
+    assert_eq!(model.to_string(), GSM);
+    let model = Model::<u128>::parse(DARC).unwrap();
+    assert_eq!(model.algorithm, CRC_82_DARC);
+    assert_eq!(model.to_string(), DARC);
+
+    for alg in [CRC_16_ARC, CRC_16_IBM_SDLC, CRC_16_KERMIT] {
+        let model = alg.model(None).to_string();
+        assert_eq!(Algorithm::<u16>::from_model_str(&model), Ok(alg));
+    }
+    let model = CRC_64_XZ.model(Some("CRC-64/XZ")).to_string();
+    assert!(
+        model.ends_with("check=0x995dc9bbdf1939fa residue=0x49958c9abd7d353f name=\"CRC-64/XZ\"")
+    );
+
+    // Fields can be in any order, and the name is optional
+    let reordered = "  check=0x29b1 residue=0x0000 width=16 poly=0x1021 refin=false refout=false init=0xffff xorout=0x0000 ";
+    assert_eq!(
+        Algorithm::<u16>::from_model_str(reordered),
+        Ok(CRC_16_IBM_3740)
+    );
+
+    let errors = [
+        ("width=16 poly", ParseModelError::Syntax),
+        ("width=16 name=\"CRC", ParseModelError::Syntax),
+        ("width=16 name=\"CRC\"x", ParseModelError::Syntax),
+        ("width=16 size=16", ParseModelError::UnknownField),
+        ("width=16 width=16", ParseModelError::DuplicateField("width")),
+        ("width=16", ParseModelError::MissingField("poly")),
+        ("width=16 poly=0x1021 init=0xffff refin=no refout=false xorout=0x0000 check=0x29b1 residue=0x0000", ParseModelError::InvalidValue("refin")),
+        ("width=16 poly=0x11021 init=0xffff refin=false refout=false xorout=0x0000 check=0x29b1 residue=0x0000", ParseModelError::InvalidValue("poly")),
+        ("width=15 poly=0x1021 init=0xffff refin=false refout=false xorout=0x0000 check=0x29b1 residue=0x0000", ParseModelError::Algorithm(AlgorithmError::Init)),
+    ];
+    for (model, err) in errors {
+        assert_eq!(Algorithm::<u16>::from_model_str(model), Err(err), "{model}");
+    }
+}
+
+#[test]
+fn checksum() {
+    use crc::poly::ParsePolyError;
+
+    let gsm = Crc::<u8>::new(&CRC_3_GSM).typed_checksum(INIT);
+    assert_eq!(gsm.value(), 0x4);
+    assert_eq!(gsm.width(), 3);
+    assert_eq!(gsm.to_string(), "4");
+    assert_eq!(*gsm.to_be_bytes(), [0x04]);
+
+    let umts = Crc::<u16, NoTable>::new(&CRC_12_UMTS).typed_checksum(INIT);
+    assert_eq!(
+        format!("{umts} {umts:x} {umts:X} {umts:#x}"),
+        "daf daf DAF 0xdaf"
+    );
+    assert_eq!(*umts.to_be_bytes(), [0x0d, 0xaf]);
+    assert_eq!(*umts.to_le_bytes(), [0xaf, 0x0d]);
+
+    let crc = Crc::<u32, Braid<5>>::new(&CRC_32_ISCSI);
+    let mut digest = crc.digest();
+    digest.update(INIT);
+    let iscsi = digest.finalize_typed();
+    assert_eq!(iscsi, crc.typed_checksum(INIT));
+    assert_eq!(format!("{iscsi:#X}"), "0xE3069283");
+    assert_eq!(
+        format!("{iscsi:?}"),
+        "Checksum { value: 0xe3069283, width: 32 }"
+    );
+
+    let crc = Crc::<u128>::new(&CRC_82_DARC);
+    let mut digest = crc.digest();
+    digest.update(INIT);
+    let darc = digest.finalize_typed();
+    assert_eq!(darc.to_string(), "09ea83f625023801fd612");
+    assert_eq!(darc.to_be_bytes().len(), 11);
+    assert_eq!(darc.to_le_bytes()[..2], [0x12, 0xd6]);
+
+    assert_eq!(Checksum::<u16>::from_hex("0xDAF", 12), Ok(umts));
+    assert_eq!(Checksum::<u128>::from_hex(&darc.to_string(), 82), Ok(darc));
+    assert_eq!(Checksum::new(0xdafu16, 12), umts);
+    let errors = [
+        ("", ParsePolyError::Empty),
+        ("0x", ParsePolyError::Empty),
+        ("+daf", ParsePolyError::InvalidDigit),
+        ("0xdag", ParsePolyError::InvalidDigit),
+        ("0x1daf", ParsePolyError::Overflow),
+    ];
+    for (src, err) in errors {
+        assert_eq!(Checksum::<u16>::from_hex(src, 12), Err(err), "{src}");
+    }
+}
+
+#[test]
+fn augmented_init() {
+    use crc::poly::Poly;
+
+    // CRC-16/SPI-FUJITSU is CRC-16/IBM-3740 with the augmented initial register 0xffff
+    let crc = Crc::<u16>::new(&CRC_16_IBM_3740);
+    let init = Poly::<u16>::direct_init(16, CRC_16_IBM_3740.poly, 0xffff);
+    assert_eq!(init, CRC_16_SPI_FUJITSU.init);
+    let mut digest = crc.digest_with_initial(init);
+    digest.update(INIT);
+    assert_eq!(digest.finalize(), CRC_16_SPI_FUJITSU.check);
+
+    for alg in [CRC_32_ISCSI, CRC_32_BZIP2, CRC_32_ISO_HDLC] {
+        let augmented = Poly::<u32>::augmented_init(32, alg.poly, alg.init);
+        assert_eq!(Poly::<u32>::direct_init(32, alg.poly, augmented), alg.init);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn registry() {
+    let mut registry = Registry::new();
+    assert_eq!(registry.names().count(), 113);
+    assert_eq!(
+        registry.get("CRC-3/GSM"),
+        Some(AnyAlgorithm::U8(&CRC_3_GSM))
+    );
+    assert_eq!(
+        registry.get("CRC-16/ISO-IEC-14443-3-A"),
+        Some(AnyAlgorithm::U16(&CRC_16_ISO_IEC_14443_3_A))
+    );
+    assert_eq!(
+        registry.algorithm::<u128>("CRC-82/DARC"),
+        Some(&CRC_82_DARC)
+    );
+    assert_eq!(registry.algorithm::<u64>("CRC-32/ISCSI"), None);
+    assert!(registry.get("CRC-32/UNKNOWN").is_none());
+
+    // Every algorithm is registered under its RevEng name
+    for name in registry.names() {
+        let crc = registry.any_crc(name).unwrap();
+        let algorithm = crc.algorithm();
+        assert_eq!(crc.checksum(INIT), check(algorithm), "{name}");
+        let mut digest = crc.digest();
+        digest.update(&INIT[..4]);
+        digest.update(&INIT[4..]);
+        assert_eq!(digest.finalize(), check(algorithm), "{name}");
+    }
+
+    let crc = registry.crc::<u32, Table<16>>("CRC-32/ISCSI").unwrap();
+    assert_eq!(crc.checksum(INIT), CRC_32_ISCSI.check);
+    let crc = registry.crc::<u32, BoxedTable<1>>("CRC-32/ISCSI").unwrap();
+    assert_eq!(crc.checksum(INIT), CRC_32_ISCSI.check);
+    assert!(registry.crc::<u16, Table<1>>("CRC-32/ISCSI").is_none());
+
+    // Vendor-specific algorithms are stored with the smallest fitting register type
+    let vendor = "width=12 poly=0x80f init=0x000 refin=false refout=true xorout=0x000 check=0xdaf residue=0x000 name=\"VENDOR/A\"";
+    let algorithm = registry.register_model(vendor).unwrap();
+    assert_eq!(algorithm, AnyAlgorithm::U16(&CRC_12_UMTS));
+    assert_eq!(registry.algorithm::<u16>("VENDOR/A"), Some(&CRC_12_UMTS));
+    assert_eq!(registry.any_crc("VENDOR/A").unwrap().checksum(INIT), 0xdaf);
+
+    let previous = registry.register("VENDOR/A", CRC_64_XZ);
+    assert_eq!(previous, Ok(Some(AnyAlgorithm::U16(&CRC_12_UMTS))));
+    assert_eq!(registry.algorithm::<u64>("VENDOR/A"), Some(&CRC_64_XZ));
+    assert_eq!(registry.names().count(), 114);
+
+    // Models whose check does not match are rejected
+    let mismatch = "width=12 poly=0x80f init=0x000 refin=false refout=true xorout=0x000 check=0xdae residue=0x000 name=\"VENDOR/B\"";
+    assert_eq!(
+        registry.register_model(mismatch),
+        Err(ParseModelError::Algorithm(AlgorithmError::CheckMismatch))
+    );
+    assert_eq!(registry.get("VENDOR/B"), None);
+
+    // So are invalid algorithms, and algorithms whose check does not match
+    let invalid = Algorithm {
+        width: 0,
+        ..CRC_32_ISCSI
+    };
+    assert_eq!(
+        registry.register("VENDOR/B", invalid),
+        Err(AlgorithmError::Width)
+    );
+    let mismatch = Algorithm {
+        check: 0,
+        ..CRC_16_ARC
+    };
+    assert_eq!(
+        registry.register("VENDOR/B", mismatch),
+        Err(AlgorithmError::CheckMismatch)
+    );
+    assert_eq!(registry.get("VENDOR/B"), None);
+
+    // Registering an equal algorithm again reuses the leaked one
+    let custom = AlgorithmBuilder::<u32>::new(32, 0x741b8cd7).build();
+    registry.register("VENDOR/C", custom).unwrap();
+    registry.register("VENDOR/D", custom).unwrap();
+    let c = registry.algorithm::<u32>("VENDOR/C").unwrap();
+    let d = registry.algorithm::<u32>("VENDOR/D").unwrap();
+    assert!(core::ptr::eq(c, d));
+    assert_eq!(registry.any_crc("VENDOR/C").unwrap().verify_check(), Ok(()));
+
+    let unnamed =
+        "width=8 poly=0x07 init=0x00 refin=false refout=false xorout=0x00 check=0xf4 residue=0x00";
+    assert_eq!(
+        registry.register_model(unnamed),
+        Err(ParseModelError::MissingField("name"))
+    );
+    assert_eq!(Registry::empty().names().count(), 0);
+}
+
+#[cfg(feature = "std")]
+fn check(algorithm: AnyAlgorithm) -> u128 {
+    match algorithm {
+        AnyAlgorithm::U8(algorithm) => algorithm.check.into(),
+        AnyAlgorithm::U16(algorithm) => algorithm.check.into(),
+        AnyAlgorithm::U32(algorithm) => algorithm.check.into(),
+        AnyAlgorithm::U64(algorithm) => algorithm.check.into(),
+        AnyAlgorithm::U128(algorithm) => algorithm.check,
+    }
+}
+
+#[test]
+fn patch() {
+    const OLD: &[u8] = b"The quick brown fox jumps over the lazy dog";
+    const NEW: &[u8] = b"The quick black fox jumps over the lazy dog";
+    let (offset, len) = (10, 5);
+    let (old, new) = (&OLD[offset..][..len], &NEW[offset..][..len]);
+
+    let algs = &[CRC_3_GSM, CRC_8_BLUETOOTH, CRC_8_SMBUS];
+    for alg in algs {
+        let crc = Crc::<u8>::new(alg);
+        let patched = crc.patch(crc.checksum(OLD), OLD.len(), offset, old, new);
+        assert_eq!(crc.checksum(NEW), patched);
+    }
+    let algs = &[CRC_12_UMTS, CRC_16_IBM_SDLC, CRC_16_XMODEM];
+    for alg in algs {
+        let crc = Crc::<u16>::new(alg);
+        let patched = crc.patch(crc.checksum(OLD), OLD.len(), offset, old, new);
+        assert_eq!(crc.checksum(NEW), patched);
+    }
+    let algs = &[CRC_24_OPENPGP, CRC_32_BZIP2, CRC_32_ISO_HDLC];
+    for alg in algs {
+        let crc = Crc::<u32>::new(alg);
+        let patched = crc.patch(crc.checksum(OLD), OLD.len(), offset, old, new);
+        assert_eq!(crc.checksum(NEW), patched);
+    }
+    let algs = &[CRC_40_GSM, CRC_64_ECMA_182, CRC_64_XZ];
+    for alg in algs {
+        let crc = Crc::<u64>::new(alg);
+        let patched = crc.patch(crc.checksum(OLD), OLD.len(), offset, old, new);
+        assert_eq!(crc.checksum(NEW), patched);
+    }
+    let algs = &[CRC_82_DARC];
+    for alg in algs {
+        let crc = Crc::<u128>::new(alg);
+        let patched = crc.patch(crc.checksum(OLD), OLD.len(), offset, old, new);
+        assert_eq!(crc.checksum(NEW), patched);
+    }
+
+    // Edits touching either end of the message
+    let crc = Crc::<u32>::new(&CRC_32_ISCSI);
+    let patched = crc.patch(crc.checksum(OLD), OLD.len(), 0, &OLD[..3], b"THE");
+    assert_eq!(
+        crc.checksum(b"THE quick brown fox jumps over the lazy dog"),
+        patched
+    );
+    let patched = crc.patch(crc.checksum(OLD), OLD.len(), 40, &OLD[40..], b"cat");
+    assert_eq!(
+        crc.checksum(b"The quick brown fox jumps over the lazy cat"),
+        patched
+    );
+}
+
+#[test]
+fn checksum_many() {
+    let inputs: &[&[u8]] = &[
+        INIT, b"", INIT_PART1, INIT_PART2, INIT, b"1", INIT, INIT_PART2, b"12",
+    ];
+
+    let crc = Crc::<u8>::new(&CRC_8_BLUETOOTH);
+    let mut out = [0; 9];
+    crc.checksum_many(inputs, &mut out);
+    for (input, out) in inputs.iter().zip(out) {
+        assert_eq!(crc.checksum(input), out);
+    }
+    let crc = Crc::<u16>::new(&CRC_16_XMODEM);
+    let mut out = [0; 9];
+    crc.checksum_many(inputs, &mut out);
+    for (input, out) in inputs.iter().zip(out) {
+        assert_eq!(crc.checksum(input), out);
+    }
+    let crc = Crc::<u32>::new(&CRC_32_ISCSI);
+    let mut out = [0; 9];
+    crc.checksum_many(inputs, &mut out);
+    for (input, out) in inputs.iter().zip(out) {
+        assert_eq!(crc.checksum(input), out);
+    }
+    let crc = Crc::<u64, Table<16>>::new(&CRC_64_ECMA_182);
+    let mut out = [0; 9];
+    crc.checksum_many(inputs, &mut out);
+    for (input, out) in inputs.iter().zip(out) {
+        assert_eq!(crc.checksum(input), out);
+    }
+    let crc = Crc::<u128>::new(&CRC_82_DARC);
+    let mut out = [0; 9];
+    crc.checksum_many(inputs, &mut out);
+    for (input, out) in inputs.iter().zip(out) {
+        assert_eq!(crc.checksum(input), out);
+    }
+}
+
+#[test]
+fn update_non_contiguous() {
+    let crc = Crc::<u32>::new(&CRC_32_ISO_HDLC);
+    let expected = crc.checksum(INIT);
+
+    let mut digest = crc.digest();
+    digest.update_slices(&[INIT_PART1, b"", INIT_PART2]);
+    assert_eq!(expected, digest.finalize());
+
+    let mut digest = crc.digest();
+    digest.update_iter(INIT.iter().copied());
+    assert_eq!(expected, digest.finalize());
+
+    let mut digest = crc.digest();
+    digest.extend(INIT_PART1);
+    INIT_PART2.iter().copied().update_digest(&mut digest);
+    assert_eq!(expected, digest.finalize());
+
+    // Longer than the internal buffer of `update_iter`
+    let bytes = (0..=255).cycle().take(1000);
+    let mut digest = crc.digest();
+    digest.update_iter(bytes.clone());
+    assert_eq!(crc.checksum(&bytes.collect::<Vec<u8>>()), digest.finalize());
+
+    #[cfg(feature = "std")]
+    {
+        use std::io::IoSlice;
+        let mut digest = crc.digest();
+        digest.update_vectored(&[IoSlice::new(INIT_PART1), IoSlice::new(INIT_PART2)]);
+        assert_eq!(expected, digest.finalize());
+    }
+}
+
+#[test]
+fn update_words() {
+    let crc = Crc::<u32>::new(&CRC_32_MPEG_2);
+    let bytes = [0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0];
+
+    let mut digest = crc.digest();
+    digest.update_u16(0x1234, Endian::Big);
+    digest.update_u16(0x7856, Endian::Little);
+    digest.update_u32(0x9abcdef0, Endian::Big);
+    assert_eq!(crc.checksum(&bytes), digest.finalize());
+
+    let mut digest = crc.digest();
+    digest.update_u64(0xf0debc9a78563412, Endian::Little);
+    assert_eq!(crc.checksum(&bytes), digest.finalize());
+
+    let mut digest = crc.digest();
+    digest.update_words_be(&[0x12345678, 0x9abcdef0]);
+    assert_eq!(crc.checksum(&bytes), digest.finalize());
+
+    let mut digest = crc.digest();
+    digest.update_words_le(&[0x78563412, 0xf0debc9a]);
+    assert_eq!(crc.checksum(&bytes), digest.finalize());
+
+    // Longer than the internal buffer of `update_words_le`
+    let words: Vec<u32> = (0..100).collect();
+    let bytes: Vec<u8> = words.iter().flat_map(|word| word.to_le_bytes()).collect();
+    let mut digest = crc.digest();
+    digest.update_words_le(&words);
+    assert_eq!(crc.checksum(&bytes), digest.finalize());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn boxed_table() {
+    // The 64 KiB table must not pass through the (small) stack
+    let handle = std::thread::Builder::new()
+        .stack_size(32 * 1024)
+        .spawn(|| {
+            let crc = Box::new(Crc::<u128, BoxedTable<16>>::new(&CRC_82_DARC));
+            let mut digest = crc.digest();
+            digest.update(INIT_PART1);
+            digest.update(INIT_PART2);
+            (crc.checksum(INIT), digest.finalize())
+        })
+        .unwrap();
+    let (checksum, digest) = handle.join().unwrap();
+    assert_eq!(CRC_82_DARC.check, checksum);
+    assert_eq!(CRC_82_DARC.check, digest);
+
+    let crc = Crc::<u32, BoxedTable<8>>::new(&CRC_32_ISCSI);
+    let expected = Crc::<u32, Table<8>>::new(&CRC_32_ISCSI);
+    assert_eq!(expected.table(), crc.table());
+    let mut digest = crc.digest_with_initial(0);
+    digest.update_words_le(&[0x12345678]);
+    let mut expected = expected.digest_with_initial(0);
+    expected.update(&0x12345678u32.to_le_bytes());
+    assert_eq!(expected.finalize(), digest.finalize());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn lazy() {
+    static ISCSI: LazyCrc<u32> = LazyCrc::new(&CRC_32_ISCSI);
+    static ECMA: LazyCrc<u64, Table<16>> = LazyCrc::new(&CRC_64_ECMA_182);
+    static DARC: LazyCrc<u128, BoxedTable<16>> = LazyCrc::new(&CRC_82_DARC);
+
+    // Digests borrow the static for `'static`, so they can be stored freely
+    let digest: Digest<'static, u32> = ISCSI.digest();
+    let handle = std::thread::spawn(move || {
+        let mut digest = digest;
+        digest.update(INIT);
+        digest.finalize()
+    });
+    assert_eq!(CRC_32_ISCSI.check, handle.join().unwrap());
+    assert_eq!(CRC_32_ISCSI.check, ISCSI.checksum(INIT));
+    assert_eq!(CRC_64_ECMA_182.check, ECMA.checksum(INIT));
+    assert_eq!(CRC_82_DARC.check, DARC.checksum(INIT));
+    assert_eq!(&CRC_82_DARC, DARC.algorithm());
+}
+
+#[test]
+fn shared_table() {
+    static TABLE_8: CrcTable<u8, 16> = CrcTable::<u8, 16>::new(&CRC_8_SMBUS);
+    static TABLE_16: CrcTable<u16, 1> = CrcTable::<u16, 1>::new(&CRC_16_KERMIT);
+    static TABLE_32: CrcTable<u32, 8> = CrcTable::<u32, 8>::new(&CRC_32_ISO_HDLC);
+    static TABLE_64: CrcTable<u64, 16> = CrcTable::<u64, 16>::new(&CRC_64_WE);
+    static TABLE_128: CrcTable<u128, 16> = CrcTable::<u128, 16>::new(&CRC_82_DARC);
+
+    // Algorithms sharing `width`, `poly` and `refin` with the table's algorithm
+    let algs = &[CRC_8_I_432_1, CRC_8_SMBUS];
+    for alg in algs {
+        let crc = Crc::<u8, SharedTable<16>>::with_table(alg, &TABLE_8);
+        assert_eq!(alg.check, crc.checksum(INIT));
+        let mut digest = crc.digest();
+        digest.update(INIT_PART1);
+        digest.update(INIT_PART2);
+        assert_eq!(alg.check, digest.finalize());
+    }
+    let algs = &[
+        CRC_16_IBM_SDLC,
+        CRC_16_KERMIT,
+        CRC_16_MCRF4XX,
+        CRC_16_TMS37157,
+    ];
+    for alg in algs {
+        let crc = Crc::<u16, SharedTable<1>>::with_table(alg, &TABLE_16);
+        assert_eq!(alg.check, crc.checksum(INIT));
+    }
+    let algs = &[CRC_32_ISO_HDLC, CRC_32_JAMCRC];
+    for alg in algs {
+        let crc = Crc::<u32, SharedTable<8>>::with_table(alg, &TABLE_32);
+        assert_eq!(alg.check, crc.checksum(INIT));
+    }
+    let algs = &[CRC_64_ECMA_182, CRC_64_WE];
+    for alg in algs {
+        let crc = Crc::<u64, SharedTable<16>>::with_table(alg, &TABLE_64);
+        assert_eq!(alg.check, crc.checksum(INIT));
+    }
+    let crc = Crc::<u128, SharedTable<16>>::with_table(&CRC_82_DARC, &TABLE_128);
+    assert_eq!(CRC_82_DARC.check, crc.checksum(INIT));
+    assert_eq!(
+        Crc::<u128, Table<16>>::new(&CRC_82_DARC).table(),
+        crc.table().table()
+    );
+}
+
+#[test]
+#[should_panic(expected = "table does not match algorithm")]
+fn shared_table_mismatch() {
+    static TABLE: CrcTable<u32, 1> = CrcTable::<u32, 1>::new(&CRC_32_ISO_HDLC);
+    let _ = Crc::<u32, SharedTable<1>>::with_table(&CRC_32_BZIP2, &TABLE);
+}
+
+#[test]
+fn chorba() {
+    let mut data = [0u8; 600];
+    for (i, byte) in data.iter_mut().enumerate() {
+        *byte = (i * 31 + i / 7) as u8;
+    }
+
+    let algs = &[
+        CRC_32_BZIP2,
+        CRC_32_CKSUM,
+        CRC_32_ISCSI,
+        CRC_32_ISO_HDLC,
+        CRC_32_JAMCRC,
+        CRC_32_MPEG_2,
+    ];
+    for alg in algs {
+        let crc = Crc::<u32, Chorba>::new(alg);
+        let expected = Crc::<u32>::new(alg);
+        assert_eq!(alg.check, crc.checksum(INIT));
+        for len in 0..data.len() {
+            assert_eq!(crc.checksum(&data[..len]), expected.checksum(&data[..len]));
+        }
+
+        let mut digest = crc.digest();
+        digest.update(&data[..333]);
+        digest.update(&data[333..]);
+        assert_eq!(digest.finalize(), expected.checksum(&data));
+    }
+
+    // The folding routine is chosen by `new`, not by the public `algorithm` field
+    let mut crc = Crc::<u32, Chorba>::new(&CRC_32_ISCSI);
+    crc.algorithm = &CRC_32_AIXM;
+    let _ = crc.checksum(&data);
+}
+
+#[test]
+#[should_panic(expected = "polynomial not supported by Chorba")]
+fn chorba_unsupported() {
+    let _ = Crc::<u32, Chorba>::new(&CRC_32_AIXM);
+}